@@ -1,8 +1,9 @@
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion};
-use sqlite_hashes::{HashState, NamedDigest};
+use sqlite_hashes::rusqlite::Connection;
+use sqlite_hashes::{register_hash_functions, HashState, NamedDigest};
 
-criterion_group!(benches, all_hash_tests);
+criterion_group!(benches, all_hash_tests, seeded_hash_tests);
 criterion_main!(benches);
 
 fn all_hash_tests(c: &mut Criterion) {
@@ -13,7 +14,30 @@ fn all_hash_tests(c: &mut Criterion) {
     hash_test::<sha2::Sha256>(&mut group);
     hash_test::<sha2::Sha384>(&mut group);
     hash_test::<sha2::Sha512>(&mut group);
+    hash_test::<sha2::Sha512_224>(&mut group);
+    hash_test::<sha2::Sha512_256>(&mut group);
+    hash_test::<sha3::Sha3_224>(&mut group);
+    hash_test::<sha3::Sha3_256>(&mut group);
+    hash_test::<sha3::Sha3_384>(&mut group);
+    hash_test::<sha3::Sha3_512>(&mut group);
+    hash_test::<sha3::Keccak256>(&mut group);
+    hash_test::<ascon_hash256::AsconHash256>(&mut group);
+    hash_test::<sm3::Sm3>(&mut group);
+    hash_test::<streebog::Streebog256>(&mut group);
+    hash_test::<streebog::Streebog512>(&mut group);
+    hash_test::<blake2::Blake2b512>(&mut group);
+    hash_test::<blake2::Blake2s256>(&mut group);
+    hash_test::<sqlite_hashes::Crc32>(&mut group);
+    hash_test::<sqlite_hashes::Crc32c>(&mut group);
+    hash_test::<sqlite_hashes::Crc64Ecma>(&mut group);
+    hash_test::<sqlite_hashes::Crc64Xz>(&mut group);
+    hash_test::<sqlite_hashes::Adler32>(&mut group);
     hash_test::<noncrypto_digests::Fnv>(&mut group);
+    hash_test::<sqlite_hashes::Fnv1a_32>(&mut group);
+    hash_test::<sqlite_hashes::Fnv1a_128>(&mut group);
+    hash_test::<sqlite_hashes::Fnv1_32>(&mut group);
+    hash_test::<sqlite_hashes::Fnv1_64>(&mut group);
+    hash_test::<sqlite_hashes::Fnv1_128>(&mut group);
     hash_test::<noncrypto_digests::Xxh32>(&mut group);
     hash_test::<noncrypto_digests::Xxh64>(&mut group);
     hash_test::<noncrypto_digests::Xxh3_64>(&mut group);
@@ -21,6 +45,38 @@ fn all_hash_tests(c: &mut Criterion) {
     group.finish();
 }
 
+/// Compare the seeded SQL functions, including the `SQLite` call overhead.
+fn seeded_hash_tests(c: &mut Criterion) {
+    let mut group = c.benchmark_group("seeded");
+    let db = Connection::open_in_memory().expect("in-memory db");
+    register_hash_functions(&db).expect("register functions");
+    for fn_name in [
+        "xxh64_seeded",
+        "xxh3_64_seeded",
+        "wyhash_final4",
+        "rapidhash_v3",
+        "komihash_v5",
+    ] {
+        sql_hash_test(&mut group, &db, fn_name);
+    }
+    group.finish();
+}
+
+fn sql_hash_test(group: &mut BenchmarkGroup<WallTime>, db: &Connection, fn_name: &str) {
+    let mut stmt = db
+        .prepare(&format!("SELECT {fn_name}(42, ?)"))
+        .expect("prepare query");
+    for size in [10, 10 * 1024, 1024 * 1024] {
+        let data = gen_data(size);
+        group.bench_function(BenchmarkId::new(fn_name, size), |b| {
+            b.iter(|| {
+                stmt.query_row([&data], |r| r.get::<_, Vec<u8>>(0))
+                    .expect("hash query")
+            });
+        });
+    }
+}
+
 fn hash_test<T: NamedDigest + Clone>(group: &mut BenchmarkGroup<WallTime>) {
     for size in [10, 10 * 1024, 1024 * 1024] {
         let data = gen_data(size);
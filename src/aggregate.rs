@@ -22,21 +22,19 @@ macro_rules! trace {
     ($($arg:tt)*) => {};
 }
 
-pub fn create_agg_function<A, D, T>(conn: &Connection, fn_name: &str, aggr: D) -> Result<()>
+pub fn create_agg_function<A, D, T>(
+    conn: &Connection,
+    fn_name: &str,
+    flags: FunctionFlags,
+    aggr: D,
+) -> Result<()>
 where
     A: RefUnwindSafe + UnwindSafe,
     D: Aggregate<A, T> + 'static,
     T: ToSql,
 {
     trace!("Registering aggregate function {fn_name}");
-    conn.create_aggregate_function(
-        fn_name,
-        -1,
-        FunctionFlags::SQLITE_UTF8
-            | FunctionFlags::SQLITE_DETERMINISTIC
-            | FunctionFlags::SQLITE_DIRECTONLY,
-        aggr,
-    )
+    conn.create_aggregate_function(fn_name, -1, flags, aggr)
 }
 
 pub struct AggType<D, R> {
@@ -0,0 +1,77 @@
+use ascon_hash256::AsconHash256;
+use ascon_xof128::{AsconCxof128, AsconXof128};
+use digest::{ExtendableOutput, TryCustomizedInit as _, Update};
+
+use crate::param::{create_param_hash_fn, ParamDigest, Params, XofLen, MAX_OUTPUT_LEN};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Ascon-CXOF128 with the output length and the customization string as the first two parameters.
+#[derive(Clone)]
+pub(crate) struct AsconCxof {
+    hasher: AsconCxof128,
+    len: usize,
+}
+
+impl ParamDigest for AsconCxof {
+    const PARAMS: usize = 2;
+
+    fn new(params: &Params) -> Result<Self> {
+        let len = params.length(0, "len", 1..=MAX_OUTPUT_LEN)?;
+        // NIST SP 800-232 limits the customization string to 2048 bits
+        let customization = params.max_bytes(1, "customization", 256)?;
+        let hasher = AsconCxof128::try_new_customized(customization)
+            .map_err(|e| params.error(1, "customization", e))?;
+        Ok(Self { hasher, len })
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        let mut output = vec![0; self.len];
+        self.hasher.finalize_xof_into(&mut output);
+        output
+    }
+}
+
+/// Register `ascon_hash256`, `ascon_xof128`, and `ascon_cxof128` SQL functions with the given `SQLite` connection.
+/// The functions compute the [Ascon](https://doi.org/10.6028/NIST.SP.800-232) lightweight hash and
+/// extendable-output functions (blob) of the argument(s), as standardized in NIST SP 800-232.
+///
+/// * `ascon_hash256(...)` is Ascon-Hash256, returning 32 bytes.
+/// * `ascon_xof128(len, ...)` is Ascon-XOF128, with the output length in bytes between 1 and 1048576.
+/// * `ascon_cxof128(len, customization, ...)` is Ascon-CXOF128, with the same output length,
+///   and the customization string of at most 256 bytes, either a string or a blob.
+///   Use an empty string for no customization.
+///
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_ascon_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_ascon_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT ascon_hash256('')", [], |r| r.get(0))?;
+/// let expected = b"\x0b\x3b\xe5\x85\x0f\x2f\x6b\x98\xca\xf2\x9f\x8f\xde\xa8\x9b\x64\xa1\xfa\x70\xaa\x24\x9b\x8f\x83\x9b\xd5\x3b\xaa\x30\x4d\x92\xb2";
+/// assert_eq!(hash, expected);
+/// let hash: Vec<u8> = db.query_row("SELECT ascon_xof128(8, '')", [], |r| r.get(0))?;
+/// assert_eq!(hash, b"\x47\x3d\x5e\x61\x64\xf5\x8b\x39");
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_ascon_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<AsconHash256>(conn, "ascon_hash256", flags)?;
+    create_param_hash_fn::<XofLen<AsconXof128>>(conn, "ascon_xof128", flags)?;
+    create_param_hash_fn::<AsconCxof>(conn, "ascon_cxof128", flags)
+}
@@ -0,0 +1,136 @@
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+use blake2::{Blake2b512, Blake2bVarCore, Blake2s256, Blake2sVarCore};
+use digest::block_api::{Block, Buffer, BufferKindUser, Lazy, VariableOutputCore};
+use digest::typenum::Unsigned;
+
+use crate::param::{create_param_hash_fn, ParamDigest, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Access to the BLAKE2 parameter block of the `blake2` crate cores.
+pub(crate) trait Blake2Core:
+    VariableOutputCore + BufferKindUser<BufferKind = Lazy> + Clone
+{
+    fn new_with_params(salt: &[u8], persona: &[u8], key_size: usize, output_size: usize) -> Self;
+}
+
+impl Blake2Core for Blake2bVarCore {
+    fn new_with_params(salt: &[u8], persona: &[u8], key_size: usize, output_size: usize) -> Self {
+        Self::new_with_params(salt, persona, key_size, output_size)
+    }
+}
+
+impl Blake2Core for Blake2sVarCore {
+    fn new_with_params(salt: &[u8], persona: &[u8], key_size: usize, output_size: usize) -> Self {
+        Self::new_with_params(salt, persona, key_size, output_size)
+    }
+}
+
+/// BLAKE2 hasher with the output length, and optionally the key, salt, and personalization,
+/// taken from the leading SQL arguments. With `KEYED = false`, only the output length is used.
+#[derive(Clone)]
+pub(crate) struct Blake2<T: Blake2Core, const KEYED: bool> {
+    core: T,
+    buffer: Buffer<T>,
+    len: usize,
+}
+
+impl<T: Blake2Core, const KEYED: bool> Blake2<T, KEYED> {
+    /// The maximum output and key length, in bytes.
+    const MAX_LEN: usize = T::OutputSize::USIZE;
+    /// The maximum salt and personalization length, in bytes. Shorter values are padded with zeros.
+    const MAX_SALT_LEN: usize = T::OutputSize::USIZE / 4;
+}
+
+impl<T, const KEYED: bool> ParamDigest for Blake2<T, KEYED>
+where
+    T: Blake2Core + 'static,
+    Self: UnwindSafe + RefUnwindSafe,
+{
+    const PARAMS: usize = if KEYED { 4 } else { 1 };
+
+    fn new(params: &Params) -> Result<Self> {
+        let len = params.length(0, "len", 1..=Self::MAX_LEN)?;
+        if !KEYED {
+            return Ok(Self {
+                core: T::new_with_params(&[], &[], 0, len),
+                buffer: Buffer::<T>::default(),
+                len,
+            });
+        }
+        let key = params.max_bytes(1, "key", Self::MAX_LEN)?;
+        let salt = params.max_bytes(2, "salt", Self::MAX_SALT_LEN)?;
+        let persona = params.max_bytes(3, "personalization", Self::MAX_SALT_LEN)?;
+        let mut hasher = Self {
+            core: T::new_with_params(salt, persona, key.len(), len),
+            buffer: Buffer::<T>::default(),
+            len,
+        };
+        if !key.is_empty() {
+            // The key is padded to a full block, and processed as the first block of data
+            let mut block = Block::<T>::default();
+            block[..key.len()].copy_from_slice(key);
+            hasher.update(&block);
+        }
+        Ok(hasher)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let Self { core, buffer, .. } = self;
+        buffer.digest_blocks(data, |blocks| core.update_blocks(blocks));
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        let mut output = digest::Output::<T>::default();
+        self.core
+            .finalize_variable_core(&mut self.buffer, &mut output);
+        output[..self.len].to_vec()
+    }
+}
+
+/// Register `blake2b512`, `blake2s256`, `blake2b`, `blake2s`, `blake2b_keyed`, and `blake2s_keyed` SQL functions
+/// with the given `SQLite` connection. The functions compute the [BLAKE2 hash](https://www.blake2.net/) (blob)
+/// of the argument(s).
+///
+/// * `blake2b512(...)` and `blake2s256(...)` return the full-length 64 and 32 byte hashes.
+/// * `blake2b(len, ...)` and `blake2s(len, ...)` take the output length in bytes as the first argument,
+///   between 1 and 64 for `BLAKE2b`, and between 1 and 32 for `BLAKE2s`.
+/// * `blake2b_keyed(len, key, salt, personalization, ...)` and `blake2s_keyed(len, key, salt, personalization, ...)`
+///   also take the key (up to 64 or 32 bytes), the salt and the personalization (up to 16 or 8 bytes),
+///   all either strings or blobs. Shorter salt and personalization are padded with zeros.
+///   Use an empty string for an unused parameter.
+///
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_blake2_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_blake2_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT blake2s(8, 'hello')", [], |r| r.get(0))?;
+/// let expected = b"\x51\x93\x81\x5f\x19\x64\x4c\x56";
+/// assert_eq!(hash, expected);
+/// let hash: Vec<u8> = db.query_row("SELECT blake2b_keyed(8, 'key', 'salt', 'app-v1', 'hello')", [], |r| r.get(0))?;
+/// let expected = b"\x50\x5e\xaf\x8c\xa1\x6c\xea\x02";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_blake2_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Blake2b512>(conn, "blake2b512", flags)?;
+    create_hash_fn::<Blake2s256>(conn, "blake2s256", flags)?;
+    create_param_hash_fn::<Blake2<Blake2bVarCore, false>>(conn, "blake2b", flags)?;
+    create_param_hash_fn::<Blake2<Blake2sVarCore, false>>(conn, "blake2s", flags)?;
+    create_param_hash_fn::<Blake2<Blake2bVarCore, true>>(conn, "blake2b_keyed", flags)?;
+    create_param_hash_fn::<Blake2<Blake2sVarCore, true>>(conn, "blake2s_keyed", flags)
+}
@@ -1,12 +1,63 @@
 use blake3::Hasher;
 
+use crate::param::{create_param_hash_fn, ParamDigest, Params, XofLen};
+use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::{Connection, Result};
-use crate::scalar::create_hash_fn;
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
 
-/// Register the `blake3` SQL function with the given `SQLite` connection.
-/// The function takes a single argument and returns the [BLAKE3 hash](https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3) (blob) of that argument.
-/// The argument can be either a string or a blob.
-/// If the argument is `NULL`, the result is `NULL`.
+/// BLAKE3 in the keyed hashing mode, with the 32-byte key as the first parameter.
+#[derive(Clone)]
+pub(crate) struct Blake3Keyed(Hasher);
+
+impl ParamDigest for Blake3Keyed {
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        Ok(Self(Hasher::new_keyed(params.key(0, "key")?)))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.0.finalize().as_bytes().to_vec()
+    }
+}
+
+/// BLAKE3 in the key derivation mode, with the context string as the first parameter.
+#[derive(Clone)]
+pub(crate) struct Blake3DeriveKey(Hasher);
+
+impl ParamDigest for Blake3DeriveKey {
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        Ok(Self(Hasher::new_derive_key(params.text(0, "context")?)))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.0.finalize().as_bytes().to_vec()
+    }
+}
+
+/// Register the `blake3`, `blake3_keyed`, `blake3_derive_key`, and `blake3_xof` SQL functions
+/// with the given `SQLite` connection. The functions compute the
+/// [BLAKE3 hash](https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3) (blob) of the argument(s).
+///
+/// * `blake3(...)` returns the 32 byte hash.
+/// * `blake3_keyed(key, ...)` returns the 32 byte keyed hash (MAC). The key must be exactly 32 bytes.
+/// * `blake3_derive_key(context, ...)` returns the 32 byte key derived from the other arguments (key material).
+///   The context must be a UTF-8 string, which should be hardcoded, globally unique, and application-specific.
+/// * `blake3_xof(len, ...)` returns the extended output of `len` bytes, between 1 and 1048576.
+///   The first 32 bytes are the same as the `blake3(...)` hash.
+///
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
 ///
 /// # Example
 ///
@@ -19,9 +70,18 @@ use crate::scalar::create_hash_fn;
 /// let hash: Vec<u8> = db.query_row("SELECT blake3('hello')", [], |r| r.get(0))?;
 /// let expected = b"\xea\x8f\x16\x3d\xb3\x86\x82\x92\x5e\x44\x91\xc5\xe5\x8d\x4b\xb3\x50\x6e\xf8\xc1\x4e\xb7\x8a\x86\xe9\x08\xc5\x62\x4a\x67\x20\x0f";
 /// assert_eq!(hash, expected);
+/// let hash: Vec<u8> = db.query_row("SELECT blake3_xof(4, 'hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, b"\xea\x8f\x16\x3d");
 /// # Ok(())
 /// # }
 /// ```
 pub fn register_blake3_functions(conn: &Connection) -> Result<()> {
-    create_hash_fn::<Hasher>(conn, "blake3")
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Hasher>(conn, "blake3", flags)?;
+    create_param_hash_fn::<Blake3Keyed>(conn, "blake3_keyed", flags)?;
+    create_param_hash_fn::<Blake3DeriveKey>(conn, "blake3_derive_key", flags)?;
+    create_param_hash_fn::<XofLen<Hasher>>(conn, "blake3_xof", flags)
 }
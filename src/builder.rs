@@ -0,0 +1,183 @@
+use std::borrow::Cow;
+use std::ops::BitOr;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_variants, NamedDigest, DIRECT_ONLY_FLAGS};
+
+/// A set of function variants to register for each hashing algorithm.
+/// Variants can be combined with the `|` operator, e.g. `Variants::SCALAR | Variants::HEX`.
+/// Only the variants enabled by the crate features are available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Variants(u8);
+
+impl Variants {
+    /// No variants. Useful as a starting point when building the set at runtime.
+    pub const NONE: Self = Self(0);
+    /// The blob-returning scalar function, e.g. `sha256(...)`
+    pub const SCALAR: Self = Self(1);
+    /// The hex-returning scalar function, e.g. `sha256_hex(...)`
+    #[cfg(feature = "hex")]
+    pub const HEX: Self = Self(1 << 1);
+    /// The blob-returning aggregate function, e.g. `sha256_concat(...)`
+    #[cfg(feature = "aggregate")]
+    pub const CONCAT: Self = Self(1 << 2);
+    /// The hex-returning aggregate function, e.g. `sha256_concat_hex(...)`
+    #[cfg(all(feature = "aggregate", feature = "hex"))]
+    pub const CONCAT_HEX: Self = Self(1 << 3);
+    /// The HMAC counterparts of all other selected variants, e.g. `hmac_sha256(key, ...)` and `hmac_sha256_hex(key, ...)`.
+    /// Only registered for the digests with a block size, see [`NamedDigest::block_size`].
+    #[cfg(feature = "hmac")]
+    pub const HMAC: Self = Self(1 << 4);
+    /// The truncated counterparts of all other selected variants except HMAC,
+    /// e.g. `sha256_trunc(len, ...)` and `sha256_trunc_hex(len, ...)`.
+    pub const TRUNC: Self = Self(1 << 5);
+    /// All variants enabled by the crate features.
+    pub const ALL: Self = Self::all();
+
+    const fn all() -> Self {
+        #[allow(unused_mut, reason = "not every feature combination adds a variant")]
+        let mut all = Self::SCALAR.0 | Self::TRUNC.0;
+        #[cfg(feature = "hex")]
+        {
+            all |= Self::HEX.0;
+        }
+        #[cfg(feature = "aggregate")]
+        {
+            all |= Self::CONCAT.0;
+        }
+        #[cfg(all(feature = "aggregate", feature = "hex"))]
+        {
+            all |= Self::CONCAT_HEX.0;
+        }
+        #[cfg(feature = "hmac")]
+        {
+            all |= Self::HMAC.0;
+        }
+        Self(all)
+    }
+
+    /// Returns `true` if all variants in `other` are also in `self`.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Variants {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+type RegisterFn = fn(&Connection, &str, &str, Variants, FunctionFlags) -> Result<()>;
+
+/// Entry point for the runtime-configurable registration of hashing functions.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::{HashFunctions, Variants};
+/// # fn main() -> Result<()> {
+/// # #[cfg(all(feature = "hex", feature = "sha256", feature = "md5"))]
+/// # {
+/// let db = Connection::open_in_memory()?;
+/// HashFunctions::builder()
+///     .algorithm::<sha2::Sha256>()
+///     .algorithm_as::<md5::Md5>("legacy_md5")
+///     .variants(Variants::SCALAR | Variants::HEX)
+///     .prefix("h_")
+///     .register(&db)?;
+/// let hash: String = db.query_row("SELECT h_sha256_hex('hello')", [], |r| r.get(0))?;
+/// assert_eq!(&hash, "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824");
+/// let hash: String = db.query_row("SELECT h_legacy_md5_hex('hello')", [], |r| r.get(0))?;
+/// assert_eq!(&hash, "5D41402ABC4B2A76B9719D911017C592");
+/// assert!(db.query_row("SELECT sha256('hello')", [], |r| r.get::<_, Vec<u8>>(0)).is_err());
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct HashFunctions;
+
+impl HashFunctions {
+    /// Create a new builder with no algorithms, all variants, no prefix,
+    /// and the same flags as the `register_*_functions` functions.
+    #[must_use]
+    pub fn builder() -> HashFunctionsBuilder {
+        HashFunctionsBuilder::default()
+    }
+}
+
+/// Builder to choose which hashing functions get registered, under which names, and with which flags.
+/// The same builder can be used to register functions with multiple connections.
+#[derive(Debug, Clone)]
+pub struct HashFunctionsBuilder {
+    algorithms: Vec<(Cow<'static, str>, RegisterFn)>,
+    variants: Variants,
+    prefix: String,
+    flags: FunctionFlags,
+}
+
+impl Default for HashFunctionsBuilder {
+    fn default() -> Self {
+        Self {
+            algorithms: Vec::new(),
+            variants: Variants::ALL,
+            prefix: String::new(),
+            flags: DIRECT_ONLY_FLAGS,
+        }
+    }
+}
+
+impl HashFunctionsBuilder {
+    /// Add a hashing algorithm, using [`NamedDigest::name`] as the base function name.
+    #[must_use]
+    pub fn algorithm<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static>(self) -> Self {
+        self.algorithm_as::<T>(T::name())
+    }
+
+    /// Add a hashing algorithm under a custom base function name.
+    #[must_use]
+    pub fn algorithm_as<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static>(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.algorithms
+            .push((name.into(), create_hash_variants::<T>));
+        self
+    }
+
+    /// Set which function variants to register for each algorithm. Defaults to [`Variants::ALL`].
+    #[must_use]
+    pub fn variants(mut self, variants: Variants) -> Self {
+        self.variants = variants;
+        self
+    }
+
+    /// Set a prefix prepended to every function name, e.g. `h_` registers `h_sha256`, `h_sha256_hex`, `h_hmac_sha256`, etc.
+    #[must_use]
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Set the `SQLite` function flags. Defaults to `SQLITE_UTF8 | SQLITE_DETERMINISTIC | SQLITE_DIRECTONLY`.
+    #[must_use]
+    pub fn flags(mut self, flags: FunctionFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Register all configured functions with the given `SQLite` connection.
+    pub fn register(&self, conn: &Connection) -> Result<()> {
+        for (name, register) in &self.algorithms {
+            register(conn, &self.prefix, name, self.variants, self.flags)?;
+        }
+        Ok(())
+    }
+}
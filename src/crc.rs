@@ -0,0 +1,125 @@
+use adler2::Adler32 as Adler32Hasher;
+use crc::{Crc, CRC_32_ISCSI, CRC_32_ISO_HDLC, CRC_64_ECMA_182, CRC_64_XZ};
+use digest::consts::{U4, U8};
+use digest::{FixedOutput, HashMarker, Output, OutputSizeUser, Update};
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, create_int_hash_fn, DIRECT_ONLY_FLAGS};
+
+macro_rules! crc_digest {
+    ($(#[$doc:meta])* $name:ident, $int:ty, $size:ty, $algorithm:ident) => {
+        $(#[$doc])*
+        #[derive(Clone)]
+        pub struct $name(crc::Digest<'static, $int>);
+
+        impl Default for $name {
+            fn default() -> Self {
+                static CRC: Crc<$int> = Crc::<$int>::new(&$algorithm);
+                Self(CRC.digest())
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $size;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                self.0.update(data);
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.0.finalize().to_be_bytes());
+            }
+        }
+    };
+}
+
+crc_digest!(
+    /// CRC-32 checksum (CRC-32/ISO-HDLC), as used by zip, gzip, and PNG, with the big-endian output.
+    Crc32, u32, U4, CRC_32_ISO_HDLC
+);
+crc_digest!(
+    /// CRC-32C checksum (CRC-32/ISCSI, Castagnoli), as used by iSCSI and ext4, with the big-endian output.
+    Crc32c, u32, U4, CRC_32_ISCSI
+);
+crc_digest!(
+    /// CRC-64/ECMA-182 checksum, with the big-endian output.
+    Crc64Ecma, u64, U8, CRC_64_ECMA_182
+);
+crc_digest!(
+    /// CRC-64/XZ checksum, the reflected variant of the ECMA-182 polynomial, as used by xz,
+    /// with the big-endian output.
+    Crc64Xz, u64, U8, CRC_64_XZ
+);
+
+/// Adler-32 checksum, as used by zlib, with the big-endian output.
+#[derive(Clone, Default)]
+pub struct Adler32(Adler32Hasher);
+
+impl HashMarker for Adler32 {}
+
+impl OutputSizeUser for Adler32 {
+    type OutputSize = U4;
+}
+
+impl Update for Adler32 {
+    fn update(&mut self, data: &[u8]) {
+        self.0.write_slice(data);
+    }
+}
+
+impl FixedOutput for Adler32 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.0.checksum().to_be_bytes());
+    }
+}
+
+/// Register the `crc32`, `crc32c`, `crc64_ecma`, `crc64_xz`, and `adler32` SQL functions with the given `SQLite` connection.
+/// The functions compute the [CRC](https://en.wikipedia.org/wiki/Cyclic_redundancy_check) and
+/// [Adler-32](https://en.wikipedia.org/wiki/Adler-32) checksums (big-endian blob) of the argument(s).
+///
+/// * `crc32` is the CRC-32 used by zip, gzip, and PNG (CRC-32/ISO-HDLC).
+/// * `crc32c` is the Castagnoli CRC-32C used by iSCSI and ext4 (CRC-32/ISCSI).
+/// * `crc64_ecma` is CRC-64/ECMA-182, and `crc64_xz` is its reflected variant CRC-64/XZ, also known as CRC-64/GO-ECMA.
+/// * `adler32` is the Adler-32 checksum used by zlib.
+///
+/// Each function also has the `*_int(...)` and `*_int_concat(...)` variants, returning the checksum as an integer.
+/// The 64-bit checksums above `9223372036854775807` are returned as negative integers (two's complement).
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_crc_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_crc_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT crc32('123456789')", [], |r| r.get(0))?;
+/// assert_eq!(hash, b"\xCB\xF4\x39\x26");
+/// let hash: i64 = db.query_row("SELECT crc32_int('123456789')", [], |r| r.get(0))?;
+/// assert_eq!(hash, 0xCBF4_3926);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_crc_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Crc32>(conn, "crc32", flags)?;
+    create_int_hash_fn::<Crc32>(conn, "crc32", flags)?;
+    create_hash_fn::<Crc32c>(conn, "crc32c", flags)?;
+    create_int_hash_fn::<Crc32c>(conn, "crc32c", flags)?;
+    create_hash_fn::<Crc64Ecma>(conn, "crc64_ecma", flags)?;
+    create_int_hash_fn::<Crc64Ecma>(conn, "crc64_ecma", flags)?;
+    create_hash_fn::<Crc64Xz>(conn, "crc64_xz", flags)?;
+    create_int_hash_fn::<Crc64Xz>(conn, "crc64_xz", flags)?;
+    create_hash_fn::<Adler32>(conn, "adler32", flags)?;
+    create_int_hash_fn::<Adler32>(conn, "adler32", flags)
+}
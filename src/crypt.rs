@@ -0,0 +1,398 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use digest::{Digest, Output};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+
+use crate::param::{ct_eq, has_args, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::Error::UserFunctionError;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_scalar_function, DIRECT_ONLY_FLAGS, PASSWORD_FLAGS};
+
+/// The longest password accepted by the crypt functions.
+const MAX_PASSWORD_LEN: usize = 4096;
+/// Alphabet used by crypt(3) for the salts and the encoded hashes.
+const CRYPT_ALPHABET: &[u8; 64] =
+    b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const SHA_CRYPT_DEFAULT_ROUNDS: usize = 5000;
+/// SHA-crypt rounds limit, well below the 999999999 allowed by the spec, to keep a single call fast.
+const SHA_CRYPT_ROUNDS: std::ops::RangeInclusive<usize> = 1000..=1_000_000;
+const SSHA_SALT_LEN: usize = 8;
+
+/// Order in which the crypt(3) formats encode the digest bytes, three bytes at a time.
+const MD5_CRYPT_ORDER: [usize; 16] = [0, 6, 12, 1, 7, 13, 2, 8, 14, 3, 9, 15, 4, 10, 5, 11];
+const SHA256_CRYPT_ORDER: [usize; 32] = [
+    0, 10, 20, 21, 1, 11, 12, 22, 2, 3, 13, 23, 24, 4, 14, 15, 25, 5, 6, 16, 26, 27, 7, 17, 18, 28,
+    8, 9, 19, 29, 31, 30,
+];
+const SHA512_CRYPT_ORDER: [usize; 64] = [
+    0, 21, 42, 22, 43, 1, 44, 2, 23, 3, 24, 45, 25, 46, 4, 47, 5, 26, 6, 27, 48, 28, 49, 7, 50, 8,
+    29, 9, 30, 51, 31, 52, 10, 53, 11, 32, 12, 33, 54, 34, 55, 13, 56, 14, 35, 15, 36, 57, 37, 58,
+    16, 59, 17, 38, 18, 39, 60, 40, 61, 19, 62, 20, 41, 63,
+];
+
+/// Legacy password hash formats used by crypt(3) and LDAP directories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scheme {
+    Md5Crypt,
+    Sha256Crypt,
+    Sha512Crypt,
+    Sha,
+    Ssha,
+}
+
+impl Scheme {
+    const ALL: [Self; 5] = [
+        Self::Md5Crypt,
+        Self::Sha256Crypt,
+        Self::Sha512Crypt,
+        Self::Sha,
+        Self::Ssha,
+    ];
+    const NAMES: &'static str = "$1$, $5$, $6$, {SHA}, or {SSHA}";
+
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Md5Crypt => "$1$",
+            Self::Sha256Crypt => "$5$",
+            Self::Sha512Crypt => "$6$",
+            Self::Sha => "{SHA}",
+            Self::Ssha => "{SSHA}",
+        }
+    }
+
+    /// Find the scheme by its name, i.e. the prefix of its hashes.
+    fn new(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.prefix().eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("must be one of {}, got {name}", Self::NAMES))
+    }
+
+    /// Find the scheme of a stored hash, and return the rest of the hash after the prefix.
+    fn detect(stored: &str) -> Result<(Self, &str), String> {
+        Self::ALL
+            .into_iter()
+            .find_map(|s| {
+                let prefix = s.prefix();
+                let head = stored.get(..prefix.len())?;
+                head.eq_ignore_ascii_case(prefix)
+                    .then(|| (s, &stored[prefix.len()..]))
+            })
+            .ok_or_else(|| format!("must start with one of {}", Self::NAMES))
+    }
+
+    fn has_rounds(self) -> bool {
+        matches!(self, Self::Sha256Crypt | Self::Sha512Crypt)
+    }
+
+    /// Hash the password with a random salt, using custom `rounds` if given.
+    fn hash(self, password: &[u8], rounds: Option<usize>) -> Result<String, String> {
+        Ok(match self {
+            Self::Md5Crypt => md5_crypt(password, &crypt_salt::<8>()?),
+            Self::Sha256Crypt => sha_crypt::<Sha256>(self, password, &crypt_salt::<16>()?, rounds),
+            Self::Sha512Crypt => sha_crypt::<Sha512>(self, password, &crypt_salt::<16>()?, rounds),
+            Self::Sha => format!("{{SHA}}{}", STANDARD.encode(Sha1::digest(password))),
+            Self::Ssha => {
+                let mut salt = [0; SSHA_SALT_LEN];
+                random_fill(&mut salt)?;
+                ssha(password, &salt)
+            }
+        })
+    }
+
+    /// Check the password against the stored hash, where `rest` is the stored hash after the prefix.
+    fn verify(self, rest: &str, password: &[u8]) -> Result<bool, String> {
+        let expected = match self {
+            Self::Md5Crypt => md5_crypt(password, crypt_salt_of(rest, 8)?),
+            Self::Sha256Crypt | Self::Sha512Crypt => {
+                let (rounds, rest) = match rest.strip_prefix("rounds=") {
+                    Some(rest) => {
+                        let (rounds, rest) = rest.split_once('$').ok_or("has invalid rounds")?;
+                        let rounds: usize = rounds
+                            .parse()
+                            .map_err(|_| format!("has invalid rounds {rounds}"))?;
+                        // Too costly to compute, so reject it like password_verify() does
+                        let limit = *SHA_CRYPT_ROUNDS.end();
+                        if rounds > limit {
+                            return Err(format!(
+                                "has rounds {rounds} exceeding the limit of {limit}"
+                            ));
+                        }
+                        (Some(rounds), rest)
+                    }
+                    None => (None, rest),
+                };
+                let salt = crypt_salt_of(rest, 16)?;
+                if self == Self::Sha256Crypt {
+                    sha_crypt::<Sha256>(self, password, salt, rounds)
+                } else {
+                    sha_crypt::<Sha512>(self, password, salt, rounds)
+                }
+            }
+            Self::Sha => format!("{{SHA}}{}", STANDARD.encode(Sha1::digest(password))),
+            Self::Ssha => {
+                let decoded = STANDARD
+                    .decode(rest)
+                    .map_err(|e| format!("is not valid base64: {e}"))?;
+                if decoded.len() <= 20 {
+                    return Err("is too short for {SSHA}".to_string());
+                }
+                ssha(password, &decoded[20..])
+            }
+        };
+        // The scheme prefix is case-insensitive, so only compare the rest
+        let expected = &expected[self.prefix().len()..];
+        Ok(ct_eq(expected.as_bytes(), rest.as_bytes()))
+    }
+}
+
+fn random_fill(buf: &mut [u8]) -> Result<(), String> {
+    getrandom::fill(buf).map_err(|e| format!("failed to generate a salt: {e}"))
+}
+
+/// Generate a random crypt(3) salt of `N` characters.
+fn crypt_salt<const N: usize>() -> Result<String, String> {
+    let mut salt = [0; N];
+    random_fill(&mut salt)?;
+    Ok(salt
+        .iter()
+        .map(|b| char::from(CRYPT_ALPHABET[usize::from(b & 0x3f)]))
+        .collect())
+}
+
+/// Get the salt of a stored crypt(3) hash, i.e. up to `max` characters before the next `$`.
+fn crypt_salt_of(rest: &str, max: usize) -> Result<&str, String> {
+    let (salt, _) = rest.split_once('$').ok_or("has no salt")?;
+    Ok(salt.get(..max).unwrap_or(salt))
+}
+
+/// Encode the digest bytes in the given order using the crypt(3) alphabet, least significant bits first.
+fn crypt_encode(hash: &[u8], order: &[usize]) -> String {
+    let mut result = String::with_capacity(order.len() * 4 / 3 + 1);
+    for group in order.chunks(3) {
+        let mut value = group
+            .iter()
+            .fold(0_u32, |acc, &idx| (acc << 8) | u32::from(hash[idx]));
+        for _ in 0..=group.len() {
+            result.push(char::from(CRYPT_ALPHABET[(value & 0x3f) as usize]));
+            value >>= 6;
+        }
+    }
+    result
+}
+
+/// Repeat the bytes of `value` until they fill `len` bytes.
+fn repeat_to(value: &[u8], len: usize) -> Vec<u8> {
+    value.iter().copied().cycle().take(len).collect()
+}
+
+/// The MD5-based crypt(3) by Poul-Henning Kamp, as used in the `$1$` hashes.
+fn md5_crypt(password: &[u8], salt: &str) -> String {
+    let salt_bytes = salt.as_bytes();
+    let alternate = Md5::new()
+        .chain_update(password)
+        .chain_update(salt_bytes)
+        .chain_update(password)
+        .finalize();
+    let mut hasher = Md5::new()
+        .chain_update(password)
+        .chain_update("$1$")
+        .chain_update(salt_bytes)
+        .chain_update(repeat_to(&alternate, password.len()));
+    let mut len = password.len();
+    while len > 0 {
+        hasher.update(if len & 1 == 1 { &[0] } else { &password[..1] });
+        len >>= 1;
+    }
+    let mut hash = hasher.finalize();
+    for round in 0..1000 {
+        let mut hasher = Md5::new();
+        if round & 1 == 1 {
+            hasher.update(password);
+        } else {
+            hasher.update(hash);
+        }
+        if round % 3 != 0 {
+            hasher.update(salt_bytes);
+        }
+        if round % 7 != 0 {
+            hasher.update(password);
+        }
+        if round & 1 == 1 {
+            hasher.update(hash);
+        } else {
+            hasher.update(password);
+        }
+        hash = hasher.finalize();
+    }
+    format!("$1${salt}${}", crypt_encode(&hash, &MD5_CRYPT_ORDER))
+}
+
+/// The SHA-256 and SHA-512 based crypt(3) by Ulrich Drepper, as used in the `$5$` and `$6$` hashes.
+/// The rounds are only included in the result if they were explicitly given.
+/// Rounds below 1000 are computed as 1000, as required by the spec, but included in the result as given.
+fn sha_crypt<D: Digest>(
+    scheme: Scheme,
+    password: &[u8],
+    salt: &str,
+    rounds: Option<usize>,
+) -> String {
+    let salt_bytes = salt.as_bytes();
+    let alternate: Output<D> = D::new()
+        .chain_update(password)
+        .chain_update(salt_bytes)
+        .chain_update(password)
+        .finalize();
+    let mut hasher = D::new()
+        .chain_update(password)
+        .chain_update(salt_bytes)
+        .chain_update(repeat_to(&alternate, password.len()));
+    let mut len = password.len();
+    while len > 0 {
+        if len & 1 == 1 {
+            hasher.update(&alternate);
+        } else {
+            hasher.update(password);
+        }
+        len >>= 1;
+    }
+    let mut hash = hasher.finalize();
+
+    let mut hasher = D::new();
+    for _ in 0..password.len() {
+        hasher.update(password);
+    }
+    let p_bytes = repeat_to(&hasher.finalize(), password.len());
+    let mut hasher = D::new();
+    for _ in 0..16 + usize::from(hash[0]) {
+        hasher.update(salt_bytes);
+    }
+    let s_bytes = repeat_to(&hasher.finalize(), salt_bytes.len());
+
+    let count = rounds.map_or(SHA_CRYPT_DEFAULT_ROUNDS, |r| {
+        r.max(*SHA_CRYPT_ROUNDS.start())
+    });
+    for round in 0..count {
+        let mut hasher = D::new();
+        if round & 1 == 1 {
+            hasher.update(&p_bytes);
+        } else {
+            hasher.update(&hash);
+        }
+        if round % 3 != 0 {
+            hasher.update(&s_bytes);
+        }
+        if round % 7 != 0 {
+            hasher.update(&p_bytes);
+        }
+        if round & 1 == 1 {
+            hasher.update(&hash);
+        } else {
+            hasher.update(&p_bytes);
+        }
+        hash = hasher.finalize();
+    }
+
+    let (prefix, order) = if scheme == Scheme::Sha256Crypt {
+        ("$5$", &SHA256_CRYPT_ORDER[..])
+    } else {
+        ("$6$", &SHA512_CRYPT_ORDER[..])
+    };
+    let rounds = rounds.map_or_else(String::new, |r| format!("rounds={r}$"));
+    format!("{prefix}{rounds}{salt}${}", crypt_encode(&hash, order))
+}
+
+/// The salted SHA-1 hash used by LDAP directories, with the salt appended to the digest.
+fn ssha(password: &[u8], salt: &[u8]) -> String {
+    let mut value = Sha1::new()
+        .chain_update(password)
+        .chain_update(salt)
+        .finalize()
+        .to_vec();
+    value.extend_from_slice(salt);
+    format!("{{SSHA}}{}", STANDARD.encode(value))
+}
+
+/// Register the `crypt_hash` and `crypt_verify` SQL functions with the given `SQLite` connection.
+///
+/// * `crypt_verify(stored, password)` returns `1` if the password matches the stored hash, and `0` otherwise.
+///   The stored hash can be in the crypt(3) MD5-crypt `$1$`, SHA-256-crypt `$5$`, or SHA-512-crypt `$6$` format,
+///   or in the LDAP `{SHA}` or `{SSHA}` format. Stored `rounds` below 1000 are computed as 1000, as required
+///   by the spec, and stored `rounds` above 1000000 are too costly to verify, so they are rejected with an error.
+/// * `crypt_hash(scheme, password [, rounds])` hashes the password with a random salt, where `scheme`
+///   is one of `$1$`, `$5$`, `$6$`, `{SHA}`, or `{SSHA}`. The optional `rounds` is only supported by `$5$`
+///   and `$6$`, and must be between 1000 and 1000000, defaulting to 5000.
+///
+/// These formats are only meant for verifying and migrating existing hashes,
+/// new hashes should use a modern algorithm like Argon2id with the `password_hash` function.
+/// If the password or the stored hash is `NULL`, the result is `NULL`.
+/// Just like the password functions, these functions are not registered as `SQLITE_DETERMINISTIC`,
+/// and are always registered as `SQLITE_DIRECTONLY`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_crypt_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_crypt_functions(&db)?;
+/// let stored = "$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5";
+/// let valid: bool = db.query_row("SELECT crypt_verify(?, 'Hello world!')", [stored], |r| r.get(0))?;
+/// assert!(valid);
+/// let hash: String = db.query_row("SELECT crypt_hash('$6$', 'Hello world!', 10000)", [], |r| r.get(0))?;
+/// assert!(hash.starts_with("$6$rounds=10000$"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_crypt_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+/// The flags of the registration mode are ignored, the functions always use [`PASSWORD_FLAGS`].
+pub(crate) fn register(conn: &Connection, _flags: FunctionFlags) -> Result<()> {
+    let flags = PASSWORD_FLAGS;
+
+    create_scalar_function(conn, "crypt_hash", flags, |ctx| {
+        if !has_args(ctx, 2, 3)? {
+            return Ok(None);
+        }
+        let params = Params::new("crypt_hash", ctx, ctx.len());
+        let scheme =
+            Scheme::new(params.text(0, "scheme")?).map_err(|e| params.error(0, "scheme", e))?;
+        let password = params.max_bytes(1, "password", MAX_PASSWORD_LEN)?;
+        let rounds = params
+            .is_set(2)
+            .then(|| params.length(2, "rounds", SHA_CRYPT_ROUNDS))
+            .transpose()?;
+        if rounds.is_some() && !scheme.has_rounds() {
+            return Err(params.error(
+                2,
+                "rounds",
+                format_args!("is not supported by {}", scheme.prefix()),
+            ));
+        }
+        scheme
+            .hash(password, rounds)
+            .map(Some)
+            .map_err(|e| UserFunctionError(format!("crypt_hash: {e}").into()))
+    })?;
+
+    create_scalar_function(conn, "crypt_verify", flags, |ctx| {
+        if !has_args(ctx, 2, 2)? {
+            return Ok(None);
+        }
+        let params = Params::new("crypt_verify", ctx, 2);
+        let stored = params.text(0, "stored")?;
+        let password = params.max_bytes(1, "password", MAX_PASSWORD_LEN)?;
+        Scheme::detect(stored)
+            .and_then(|(scheme, rest)| scheme.verify(rest, password))
+            .map(Some)
+            .map_err(|e| params.error(0, "stored", e))
+    })?;
+
+    Ok(())
+}
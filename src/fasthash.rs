@@ -0,0 +1,123 @@
+use std::marker::PhantomData;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+use rapidhash::v3::{rapidhash_v3_seeded, RapidSecrets};
+use wyhash_final4::generics::WyHasher;
+use wyhash_final4::WyHash64;
+
+use crate::param::{create_param_hash_fn, create_param_int_hash_fn, ParamDigest, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::DIRECT_ONLY_FLAGS;
+
+/// A 64-bit hash function that is only defined for the whole input at once.
+pub(crate) trait OneShotHash: Clone + UnwindSafe + RefUnwindSafe + 'static {
+    fn hash(data: &[u8], seed: u64) -> u64;
+}
+
+/// wyhash, the `final4` version.
+#[derive(Clone)]
+pub(crate) struct WyhashFinal4;
+
+impl OneShotHash for WyhashFinal4 {
+    fn hash(data: &[u8], seed: u64) -> u64 {
+        WyHasher::<WyHash64>::from_seed(seed).hash(data)
+    }
+}
+
+/// rapidhash V3, seeded the same way as `rapidhash_withSeed` of the reference C++ implementation.
+#[derive(Clone)]
+pub(crate) struct RapidhashV3;
+
+impl OneShotHash for RapidhashV3 {
+    fn hash(data: &[u8], seed: u64) -> u64 {
+        rapidhash_v3_seeded(data, &RapidSecrets::seed_cpp(seed))
+    }
+}
+
+/// komihash, the version 5.
+#[derive(Clone)]
+pub(crate) struct KomihashV5;
+
+impl OneShotHash for KomihashV5 {
+    fn hash(data: &[u8], seed: u64) -> u64 {
+        komihash::v5::komihash(data, seed)
+    }
+}
+
+/// One-shot hash with the 64-bit seed as the first parameter, and the big-endian output.
+/// All other arguments are buffered, and hashed together when finalized.
+#[derive(Clone)]
+pub(crate) struct Seeded<T> {
+    seed: u64,
+    data: Vec<u8>,
+    _hash: PhantomData<T>,
+}
+
+impl<T: OneShotHash> ParamDigest for Seeded<T> {
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        Ok(Self {
+            seed: params.u64(0, "seed")?,
+            data: Vec::new(),
+            _hash: PhantomData,
+        })
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.data.extend_from_slice(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        T::hash(&self.data, self.seed).to_be_bytes().to_vec()
+    }
+}
+
+/// Register the `wyhash_final4`, `rapidhash_v3`, and `komihash_v5` SQL functions with the given `SQLite` connection.
+/// The functions compute the 64-bit hash of the argument(s), with the seed as the first argument.
+/// Negative seeds are the same as their two's complement 64-bit values.
+/// The algorithm version is part of the function name, so the results will not change when newer versions are released.
+///
+/// * `wyhash_final4(seed, ...)` is the [wyhash](https://github.com/wangyi-fudan/wyhash) `final4` version,
+///   with the default secret.
+/// * `rapidhash_v3(seed, ...)` is the [rapidhash](https://github.com/Nicoshev/rapidhash) V3 version,
+///   with the default secrets, and the seed used the same way as by the reference `rapidhash_withSeed`.
+///   This is the same as `rapidhash_v3_seeded(data, &RapidSecrets::seed_cpp(seed))` from the `rapidhash` crate.
+/// * `komihash_v5(seed, ...)` is the [komihash](https://github.com/avaneev/komihash) version 5.
+///
+/// The hash is returned as the 8-byte big-endian blob, same as the `u64` value.
+/// The `*_int(seed, ...)` variants return the hash as an integer instead.
+/// Values above `9223372036854775807` are returned as negative integers (two's complement).
+///
+/// The other arguments can be either strings or blobs, and are hashed as if they were concatenated.
+/// If all of them are `NULL`, the result is `NULL`. Unlike other functions, these algorithms
+/// cannot hash the data incrementally, so the `*_concat` aggregates keep all values in memory.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_fasthash_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_fasthash_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT wyhash_final4(1, 'a')", [], |r| r.get(0))?;
+/// assert_eq!(hash, b"\xA8\x41\x2D\x09\x1B\x5F\xE0\xA9");
+/// let hash: i64 = db.query_row("SELECT komihash_v5_int(0, 'This is a 32-byte testing string')", [], |r| r.get(0))?;
+/// assert_eq!(hash, 0x05AD_9608_0290_3A9D);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_fasthash_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_param_hash_fn::<Seeded<WyhashFinal4>>(conn, "wyhash_final4", flags)?;
+    create_param_int_hash_fn::<Seeded<WyhashFinal4>>(conn, "wyhash_final4", flags)?;
+    create_param_hash_fn::<Seeded<RapidhashV3>>(conn, "rapidhash_v3", flags)?;
+    create_param_int_hash_fn::<Seeded<RapidhashV3>>(conn, "rapidhash_v3", flags)?;
+    create_param_hash_fn::<Seeded<KomihashV5>>(conn, "komihash_v5", flags)?;
+    create_param_int_hash_fn::<Seeded<KomihashV5>>(conn, "komihash_v5", flags)
+}
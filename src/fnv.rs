@@ -1,9 +1,83 @@
+use digest::consts::{U16, U4, U8};
+use digest::{FixedOutput, HashMarker, Output, OutputSizeUser, Update};
 use noncrypto_digests::Fnv;
 
+use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
 
-/// Register the `fnv1a` SQL function with the given `SQLite` connection.
-/// The `fnv1a` function uses [Fowler–Noll–Vo hash function](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function#FNV-1a_hash) to compute the hash of the argument(s).
+macro_rules! fnv_digest {
+    ($(#[$doc:meta])* $name:ident, $int:ty, $size:ty, $offset:literal, $prime:literal, $fnv1a:literal) => {
+        $(#[$doc])*
+        #[derive(Clone, Debug)]
+        #[allow(non_camel_case_types, reason = "named after the SQL functions")]
+        pub struct $name($int);
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self($offset)
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $size;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                for byte in data {
+                    if $fnv1a {
+                        self.0 ^= <$int>::from(*byte);
+                        self.0 = self.0.wrapping_mul($prime);
+                    } else {
+                        self.0 = self.0.wrapping_mul($prime);
+                        self.0 ^= <$int>::from(*byte);
+                    }
+                }
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.0.to_be_bytes());
+            }
+        }
+    };
+}
+
+fnv_digest!(
+    /// 32-bit FNV-1 digest (multiply, then xor), with the big-endian output.
+    Fnv1_32, u32, U4, 0x811c_9dc5, 0x0100_0193, false
+);
+fnv_digest!(
+    /// 64-bit FNV-1 digest (multiply, then xor), with the big-endian output.
+    Fnv1_64, u64, U8, 0xcbf2_9ce4_8422_2325, 0x0000_0100_0000_01b3, false
+);
+fnv_digest!(
+    /// 128-bit FNV-1 digest (multiply, then xor), with the big-endian output.
+    Fnv1_128, u128, U16,
+    0x6c62_272e_07bb_0142_62b8_2175_6295_c58d,
+    0x0000_0000_0100_0000_0000_0000_0000_013b,
+    false
+);
+fnv_digest!(
+    /// 32-bit FNV-1a digest (xor, then multiply), with the big-endian output.
+    Fnv1a_32, u32, U4, 0x811c_9dc5, 0x0100_0193, true
+);
+fnv_digest!(
+    /// 128-bit FNV-1a digest (xor, then multiply), with the big-endian output.
+    Fnv1a_128, u128, U16,
+    0x6c62_272e_07bb_0142_62b8_2175_6295_c58d,
+    0x0000_0000_0100_0000_0000_0000_0000_013b,
+    true
+);
+
+/// Register the `fnv1a`, `fnv1a_32`, `fnv1a_128`, `fnv1_32`, `fnv1_64`, and `fnv1_128` SQL functions
+/// with the given `SQLite` connection. The functions use [Fowler–Noll–Vo hash function](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+/// to compute the hash of the argument(s). `fnv1a` is the 64-bit FNV-1a hash, while `fnv1_*` functions use
+/// the original FNV-1 variant, which multiplies before XOR-ing each byte. All hashes are returned as big-endian bytes.
 ///
 /// # Example
 ///
@@ -16,9 +90,21 @@ use crate::rusqlite::{Connection, Result};
 /// let hash: Vec<u8> = db.query_row("SELECT fnv1a('hello')", [], |r| r.get(0))?;
 /// let expected = b"\xA4\x30\xD8\x46\x80\xAA\xBD\x0B";
 /// assert_eq!(hash, expected);
+/// let hash: Vec<u8> = db.query_row("SELECT fnv1a_32('hello')", [], |r| r.get(0))?;
+/// let expected = b"\x4F\x9F\x2C\xAB";
+/// assert_eq!(hash, expected);
 /// # Ok(())
 /// # }
 /// ```
 pub fn register_fnv_functions(conn: &Connection) -> Result<()> {
-    crate::scalar::create_hash_fn::<Fnv>(conn, "fnv1a")
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Fnv>(conn, "fnv1a", flags)?;
+    create_hash_fn::<Fnv1a_32>(conn, "fnv1a_32", flags)?;
+    create_hash_fn::<Fnv1a_128>(conn, "fnv1a_128", flags)?;
+    create_hash_fn::<Fnv1_32>(conn, "fnv1_32", flags)?;
+    create_hash_fn::<Fnv1_64>(conn, "fnv1_64", flags)?;
+    create_hash_fn::<Fnv1_128>(conn, "fnv1_128", flags)
 }
@@ -0,0 +1,75 @@
+use highway::{HighwayHash as _, HighwayHasher, Key};
+
+use crate::param::{create_param_hash_fn, ParamDigest, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::DIRECT_ONLY_FLAGS;
+
+/// HighwayHash with the 32-byte key as the first parameter, and the output of `WORDS` 64-bit words.
+#[derive(Clone)]
+pub(crate) struct HighwayHash<const WORDS: usize>(HighwayHasher);
+
+impl<const WORDS: usize> ParamDigest for HighwayHash<WORDS> {
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        let key = params.key::<32>(0, "key")?;
+        // The key is four little-endian 64-bit words, same as in the reference implementation
+        let mut words = [0; 4];
+        for (word, chunk) in words.iter_mut().zip(key.chunks_exact(8)) {
+            *word = chunk
+                .iter()
+                .rev()
+                .fold(0, |acc, v| (acc << 8) | u64::from(*v));
+        }
+        Ok(Self(HighwayHasher::new(Key(words))))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.append(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        let words = match WORDS {
+            1 => vec![self.0.finalize64()],
+            2 => self.0.finalize128().to_vec(),
+            _ => self.0.finalize256().to_vec(),
+        };
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+}
+
+/// Register the `highwayhash64`, `highwayhash128`, and `highwayhash256` SQL functions with the given `SQLite` connection.
+/// The functions compute the [HighwayHash](https://github.com/google/highwayhash) keyed hash (blob)
+/// of the argument(s), with the key as the first argument, which must be exactly 32 bytes.
+///
+/// The 8, 16, and 32-byte results are the 64-bit words of the reference implementation, each in the little-endian
+/// byte order, same as the `Sum()` output of the Go `github.com/minio/highwayhash` package.
+///
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_highwayhash_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_highwayhash_functions(&db)?;
+/// let key = "x'000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F'";
+/// let sql = format!("SELECT highwayhash64({key}, '')");
+/// let hash: Vec<u8> = db.query_row(&sql, [], |r| r.get(0))?;
+/// assert_eq!(hash, 0x907A_56DE_22C2_6E53_u64.to_le_bytes());
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_highwayhash_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_param_hash_fn::<HighwayHash<1>>(conn, "highwayhash64", flags)?;
+    create_param_hash_fn::<HighwayHash<2>>(conn, "highwayhash128", flags)?;
+    create_param_hash_fn::<HighwayHash<4>>(conn, "highwayhash256", flags)
+}
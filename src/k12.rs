@@ -0,0 +1,70 @@
+use digest::{CustomizedInit as _, ExtendableOutput, Update};
+use k12::CustomKt;
+
+use crate::param::{create_param_hash_fn, ParamDigest, Params, MAX_OUTPUT_LEN};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::DIRECT_ONLY_FLAGS;
+
+/// [KangarooTwelve](https://www.rfc-editor.org/rfc/rfc9861.html) hasher with the output length
+/// and the customization string as the first two parameters. `RATE` is 168 for KT128, and 136 for KT256.
+#[derive(Clone)]
+pub(crate) struct Kt<const RATE: usize> {
+    hasher: CustomKt<RATE>,
+    len: usize,
+}
+
+impl<const RATE: usize> ParamDigest for Kt<RATE> {
+    const PARAMS: usize = 2;
+
+    fn new(params: &Params) -> Result<Self> {
+        let len = params.length(0, "len", 1..=MAX_OUTPUT_LEN)?;
+        let customization = params.bytes(1, "customization")?;
+        Ok(Self {
+            hasher: CustomKt::new_customized(customization),
+            len,
+        })
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        let mut output = vec![0; self.len];
+        self.hasher.finalize_xof_into(&mut output);
+        output
+    }
+}
+
+/// Register `kt128` and `kt256` SQL functions with the given `SQLite` connection.
+/// The functions compute the [KangarooTwelve](https://www.rfc-editor.org/rfc/rfc9861.html) extendable-output
+/// function (blob) of the argument(s), processing large inputs as a tree of 8 KiB chunks.
+/// `kt128` is the original KangarooTwelve, and `kt256` is its variant with the 256-bit security strength.
+///
+/// The first argument is the output length in bytes, between 1 and 1048576, and the second one is the
+/// customization string, either a string or a blob. Use an empty string for no customization.
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_k12_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_k12_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT kt128(8, '', '')", [], |r| r.get(0))?;
+/// assert_eq!(hash, b"\x1a\xc2\xd4\x50\xfc\x3b\x42\x05");
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_k12_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_param_hash_fn::<Kt<168>>(conn, "kt128", flags)?;
+    create_param_hash_fn::<Kt<136>>(conn, "kt256", flags)
+}
@@ -0,0 +1,255 @@
+use std::ops::RangeInclusive;
+
+use crate::param::{has_args, Hmac, Params};
+use crate::rusqlite::functions::{Context, FunctionFlags};
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{
+    create_scalar_function, with_hmac_digest, DigestFn, NamedDigest, DIRECT_ONLY_FLAGS,
+};
+
+/// The longest output of the `pbkdf2` function.
+const MAX_PBKDF2_LEN: usize = 1024;
+/// Total number of PBKDF2 iterations across all output blocks, enough for the OWASP recommended
+/// 1300000 iterations of PBKDF2-HMAC-SHA1, while keeping a single call within a few seconds.
+const MAX_PBKDF2_ITERATIONS: usize = 2_000_000;
+const PBKDF2_ITERATIONS: RangeInclusive<usize> = 1..=MAX_PBKDF2_ITERATIONS;
+
+/// A key derivation with all of its inputs, computed once the digest is known.
+#[derive(Debug, Clone, Copy)]
+enum Kdf<'a> {
+    Extract {
+        ikm: &'a [u8],
+        salt: &'a [u8],
+    },
+    Expand {
+        prk: &'a [u8],
+        info: &'a [u8],
+        len: usize,
+    },
+    Hkdf {
+        ikm: &'a [u8],
+        salt: &'a [u8],
+        info: &'a [u8],
+        len: usize,
+    },
+    Pbkdf2 {
+        password: &'a [u8],
+        salt: &'a [u8],
+        iterations: usize,
+        len: usize,
+    },
+}
+
+impl DigestFn for Kdf<'_> {
+    type Output = Result<Vec<u8>, String>;
+
+    fn call<T: NamedDigest + Clone>(self) -> Self::Output {
+        match self {
+            Self::Extract { ikm, salt } => Ok(hmac::<T>(salt, &[ikm])),
+            Self::Expand { prk, info, len } => hkdf_expand::<T>(prk, info, len),
+            Self::Hkdf {
+                ikm,
+                salt,
+                info,
+                len,
+            } => hkdf_expand::<T>(&hmac::<T>(salt, &[ikm]), info, len),
+            Self::Pbkdf2 {
+                password,
+                salt,
+                iterations,
+                len,
+            } => pbkdf2::<T>(password, salt, iterations, len),
+        }
+    }
+}
+
+/// Compute the HMAC of the concatenated `parts`. Only called for the digests with a block size.
+fn hmac<T: NamedDigest + Clone>(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    let mut mac = Hmac::<T>::with_key(key).unwrap_or_else(|| unreachable!());
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize()
+}
+
+/// HKDF-Expand from [RFC 5869](https://www.rfc-editor.org/rfc/rfc5869.html).
+fn hkdf_expand<T: NamedDigest + Clone>(
+    prk: &[u8],
+    info: &[u8],
+    len: usize,
+) -> Result<Vec<u8>, String> {
+    let max = 255 * <T as digest::Digest>::output_size();
+    if len > max {
+        return Err(format!("must be between 1 and {max}, got {len}"));
+    }
+    let mut okm = Vec::with_capacity(len);
+    let mut block = Vec::new();
+    let mut counter = 0_u8;
+    while okm.len() < len {
+        counter += 1;
+        block = hmac::<T>(prk, &[&block, info, &[counter]]);
+        okm.extend_from_slice(&block);
+    }
+    okm.truncate(len);
+    Ok(okm)
+}
+
+/// PBKDF2 from [RFC 8018](https://www.rfc-editor.org/rfc/rfc8018.html) with HMAC as the pseudorandom function.
+fn pbkdf2<T: NamedDigest + Clone>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: usize,
+    len: usize,
+) -> Result<Vec<u8>, String> {
+    let blocks = len.div_ceil(<T as digest::Digest>::output_size());
+    if blocks * iterations > MAX_PBKDF2_ITERATIONS {
+        return Err(format!(
+            "requires {blocks} blocks of {iterations} iterations, at most {MAX_PBKDF2_ITERATIONS} iterations in total are allowed"
+        ));
+    }
+    let mac = Hmac::<T>::with_key(password).unwrap_or_else(|| unreachable!());
+    let mut dk = Vec::with_capacity(blocks * <T as digest::Digest>::output_size());
+    for index in 1..=u32::try_from(blocks).map_err(|e| e.to_string())? {
+        let mut u = mac.clone();
+        u.update(salt);
+        u.update(&index.to_be_bytes());
+        let mut u = u.finalize();
+        let mut block = u.clone();
+        for _ in 1..iterations {
+            let mut next = mac.clone();
+            next.update(&u);
+            u = next.finalize();
+            block.iter_mut().zip(&u).for_each(|(b, v)| *b ^= v);
+        }
+        dk.extend_from_slice(&block);
+    }
+    dk.truncate(len);
+    Ok(dk)
+}
+
+/// Get an optional text or blob parameter, using an empty value for `NULL`.
+fn optional_bytes<'a>(params: &Params<'a>, idx: usize, name: &str) -> Result<&'a [u8]> {
+    if params.is_set(idx) {
+        params.bytes(idx, name)
+    } else {
+        Ok(&[])
+    }
+}
+
+/// Run the key derivation with the digest named by the first argument, reporting errors for the `len` argument.
+fn derive(params: &Params, kdf: Kdf, len_idx: usize) -> Result<Option<Vec<u8>>> {
+    let algo = params.text(0, "algo")?;
+    with_hmac_digest(algo, kdf)
+        .ok_or_else(|| {
+            params.error(
+                0,
+                "algo",
+                format_args!("must be an enabled cryptographic hashing algorithm, got {algo}"),
+            )
+        })?
+        .map(Some)
+        .map_err(|e| params.error(len_idx, "len", e))
+}
+
+/// Make sure the function got exactly `count` arguments, and get them unless the second one is `NULL`.
+fn kdf_params<'a>(
+    fn_name: &'a str,
+    ctx: &'a Context<'_>,
+    count: usize,
+) -> Result<Option<Params<'a>>> {
+    has_args(ctx, count, count)?;
+    let params = Params::new(fn_name, ctx, count);
+    Ok(params.is_set(1).then_some(params))
+}
+
+/// Register the `hkdf_extract`, `hkdf_expand`, `hkdf`, and `pbkdf2` SQL functions with the given `SQLite` connection.
+/// The first argument `algo` is the name of any enabled cryptographic hashing algorithm, e.g. `sha256` or `blake2b512`.
+///
+/// * `hkdf_extract(algo, ikm, salt)` returns the pseudorandom key from the input keying material,
+///   as defined by [RFC 5869](https://www.rfc-editor.org/rfc/rfc5869.html).
+/// * `hkdf_expand(algo, prk, info, len)` expands the pseudorandom key into `len` bytes,
+///   where `len` is at most 255 times the hash size.
+/// * `hkdf(algo, ikm, salt, info, len)` performs both steps at once.
+/// * `pbkdf2(algo, password, salt, iterations, len)` derives `len` bytes from the password,
+///   as defined by [RFC 8018](https://www.rfc-editor.org/rfc/rfc8018.html).
+///   The `len` is at most 1024 bytes, and the total number of iterations needed to compute all of the
+///   output blocks is at most 2000000. It is always registered as `SQLITE_DIRECTONLY`, even with
+///   [`register_innocuous_hash_functions`](crate::register_innocuous_hash_functions).
+///
+/// The `salt` and `info` can be `NULL`, which is the same as an empty value.
+/// If the `ikm`, `prk`, or `password` argument is `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_kdf_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_kdf_functions(&db)?;
+/// # if cfg!(feature = "sha256") {
+/// let key: Vec<u8> = db.query_row("SELECT hkdf('sha256', 'master secret', 'tenant 42', 'encryption', 32)", [], |r| r.get(0))?;
+/// assert_eq!(key.len(), 32);
+/// let key: Vec<u8> = db.query_row("SELECT pbkdf2('sha256', 'password', 'salt', 1, 20)", [], |r| r.get(0))?;
+/// assert_eq!(key, b"\x12\x0f\xb6\xcf\xfc\xf8\xb3\x2c\x43\xe7\x22\x52\x56\xc4\xf8\x37\xa8\x65\x48\xc9");
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_kdf_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_scalar_function(conn, "hkdf_extract", flags, |ctx| {
+        let Some(params) = kdf_params("hkdf_extract", ctx, 3)? else {
+            return Ok(None);
+        };
+        let kdf = Kdf::Extract {
+            ikm: params.bytes(1, "ikm")?,
+            salt: optional_bytes(&params, 2, "salt")?,
+        };
+        derive(&params, kdf, 0)
+    })?;
+
+    create_scalar_function(conn, "hkdf_expand", flags, |ctx| {
+        let Some(params) = kdf_params("hkdf_expand", ctx, 4)? else {
+            return Ok(None);
+        };
+        let kdf = Kdf::Expand {
+            prk: params.bytes(1, "prk")?,
+            info: optional_bytes(&params, 2, "info")?,
+            len: params.length(3, "len", 1..=usize::MAX)?,
+        };
+        derive(&params, kdf, 3)
+    })?;
+
+    create_scalar_function(conn, "hkdf", flags, |ctx| {
+        let Some(params) = kdf_params("hkdf", ctx, 5)? else {
+            return Ok(None);
+        };
+        let kdf = Kdf::Hkdf {
+            ikm: params.bytes(1, "ikm")?,
+            salt: optional_bytes(&params, 2, "salt")?,
+            info: optional_bytes(&params, 3, "info")?,
+            len: params.length(4, "len", 1..=usize::MAX)?,
+        };
+        derive(&params, kdf, 4)
+    })?;
+
+    // Too costly to be called implicitly from the schema, so the registration mode is ignored
+    create_scalar_function(conn, "pbkdf2", DIRECT_ONLY_FLAGS, |ctx| {
+        let Some(params) = kdf_params("pbkdf2", ctx, 5)? else {
+            return Ok(None);
+        };
+        let kdf = Kdf::Pbkdf2 {
+            password: params.bytes(1, "password")?,
+            salt: optional_bytes(&params, 2, "salt")?,
+            iterations: params.length(3, "iterations", PBKDF2_ITERATIONS)?,
+            len: params.length(4, "len", 1..=MAX_PBKDF2_LEN)?,
+        };
+        derive(&params, kdf, 4)
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use sha3::Keccak256;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Register the `keccak256` SQL function with the given `SQLite` connection.
+/// The function computes the legacy [Keccak-256 hash](https://en.wikipedia.org/wiki/SHA-3) (blob) of the argument(s),
+/// as used by Ethereum. It uses the original Keccak padding, so the result differs from `sha3_256`.
+/// The arguments can be either strings or blobs.
+/// If all arguments are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_keccak_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_keccak_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT keccak256('')", [], |r| r.get(0))?;
+/// let expected = b"\xc5\xd2\x46\x01\x86\xf7\x23\x3c\x92\x7e\x7d\xb2\xdc\xc7\x03\xc0\xe5\x00\xb6\x53\xca\x82\x27\x3b\x7b\xfa\xd8\x04\x5d\x85\xa4\x70";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_keccak_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Keccak256>(conn, "keccak256", flags)
+}
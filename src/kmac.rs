@@ -0,0 +1,94 @@
+use cshake::CShake;
+use digest::{ExtendableOutput, Update};
+
+use crate::param::{create_param_hash_fn, ParamDigest, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::DIRECT_ONLY_FLAGS;
+
+/// [KMAC](https://doi.org/10.6028/NIST.SP.800-185) message authentication code,
+/// built on top of cSHAKE with the `RATE` (168 for KMAC128, 136 for KMAC256) and the output length of `LEN` bytes.
+#[derive(Clone)]
+pub(crate) struct Kmac<const RATE: usize, const LEN: usize>(CShake<RATE>);
+
+type Kmac128 = Kmac<168, 32>;
+type Kmac256 = Kmac<136, 64>;
+
+impl<const RATE: usize, const LEN: usize> ParamDigest for Kmac<RATE, LEN> {
+    const PARAMS: usize = 2;
+
+    fn new(params: &Params) -> Result<Self> {
+        let key = params.bytes(0, "key")?;
+        let customization = params.bytes(1, "customization")?;
+        let mut hasher = CShake::<RATE>::new_with_function_name(b"KMAC", customization);
+        // bytepad(encode_string(key), RATE)
+        let mut prefix = Vec::with_capacity(RATE);
+        prefix.extend_from_slice(left_encode(RATE as u64, &mut [0; 9]));
+        prefix.extend_from_slice(left_encode(key.len() as u64 * 8, &mut [0; 9]));
+        prefix.extend_from_slice(key);
+        prefix.resize(prefix.len().next_multiple_of(RATE), 0);
+        hasher.update(&prefix);
+        Ok(Self(hasher))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        self.0.update(right_encode(LEN as u64 * 8, &mut [0; 9]));
+        let mut output = vec![0; LEN];
+        self.0.finalize_xof_into(&mut output);
+        output
+    }
+}
+
+/// Encode `value` as big-endian bytes with the leading number of bytes, per NIST SP 800-185.
+#[expect(clippy::cast_possible_truncation)]
+fn left_encode(value: u64, buf: &mut [u8; 9]) -> &[u8] {
+    buf[1..].copy_from_slice(&value.to_be_bytes());
+    let skip = buf[1..8].iter().take_while(|&&v| v == 0).count();
+    buf[skip] = (8 - skip) as u8;
+    &buf[skip..]
+}
+
+/// Encode `value` as big-endian bytes with the trailing number of bytes, per NIST SP 800-185.
+#[expect(clippy::cast_possible_truncation)]
+fn right_encode(value: u64, buf: &mut [u8; 9]) -> &[u8] {
+    buf[..8].copy_from_slice(&value.to_be_bytes());
+    let skip = buf[..7].iter().take_while(|&&v| v == 0).count();
+    buf[8] = (8 - skip) as u8;
+    &buf[skip..]
+}
+
+/// Register `kmac128` and `kmac256` SQL functions with the given `SQLite` connection.
+/// The functions compute the [KMAC](https://doi.org/10.6028/NIST.SP.800-185) message authentication code (blob)
+/// of the argument(s). The first argument is the key, and the second one is the customization string,
+/// both either strings or blobs. Use an empty string for no customization.
+/// `kmac128` returns 32 bytes, and `kmac256` returns 64 bytes.
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_kmac_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_kmac_functions(&db)?;
+/// let sql = "SELECT kmac128(x'404142434445464748494A4B4C4D4E4F505152535455565758595A5B5C5D5E5F', '', x'00010203')";
+/// let hash: Vec<u8> = db.query_row(sql, [], |r| r.get(0))?;
+/// let expected = b"\xe5\x78\x0b\x0d\x3e\xa6\xf7\xd3\xa4\x29\xc5\x70\x6a\xa4\x3a\x00\xfa\xdb\xd7\xd4\x96\x28\x83\x9e\x31\x87\x24\x3f\x45\x6e\xe1\x4e";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_kmac_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_param_hash_fn::<Kmac128>(conn, "kmac128", flags)?;
+    create_param_hash_fn::<Kmac256>(conn, "kmac256", flags)
+}
@@ -10,23 +10,57 @@
     feature = "sha256",
     feature = "sha384",
     feature = "sha512",
+    feature = "sha512_224",
+    feature = "sha512_256",
+    feature = "sha3",
+    feature = "keccak",
+    feature = "shake",
+    feature = "kmac",
+    feature = "k12",
+    feature = "turboshake",
+    feature = "ascon",
+    feature = "sm3",
+    feature = "streebog256",
+    feature = "streebog512",
+    feature = "blake2",
     feature = "blake3",
+    feature = "crc",
+    feature = "fasthash",
     feature = "fnv",
+    feature = "highwayhash",
+    feature = "murmur3",
+    feature = "siphash",
     feature = "xxhash",
+    feature = "md4",
+    feature = "ripemd160",
+    feature = "tiger",
+    feature = "whirlpool",
+    feature = "password",
+    feature = "crypt",
+    feature = "otp",
+    feature = "jws",
+    feature = "signature",
 )))]
 compile_error!(
-    "At least one of these features must be enabled: md5,sha1,sha224,sha256,sha384,sha512,blake3,fnv,xxhash"
+    "At least one of these features must be enabled: md5,sha1,sha224,sha256,sha384,sha512,sha512_224,sha512_256,sha3,keccak,shake,kmac,k12,turboshake,ascon,sm3,streebog256,streebog512,blake2,blake3,crc,fasthash,fnv,highwayhash,murmur3,siphash,xxhash,md4,ripemd160,tiger,whirlpool,password,crypt,otp,jws,signature"
 );
 
 /// Re-export of the [`rusqlite`](https://crates.io/crates/rusqlite) crate to avoid version conflicts.
 pub use rusqlite;
 
+use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::{Connection, Result};
+use crate::scalar::{DIRECT_ONLY_FLAGS, INNOCUOUS_FLAGS};
 
 mod aggregate;
 
+mod builder;
+pub use crate::builder::{HashFunctions, HashFunctionsBuilder, Variants};
+
+mod param;
+
 mod scalar;
-pub use crate::scalar::NamedDigest;
+pub use crate::scalar::{register_digest, NamedDigest};
 
 mod state;
 pub use crate::state::HashState;
@@ -67,17 +101,125 @@ mod sha512;
 #[cfg(feature = "sha512")]
 pub use crate::sha512::register_sha512_functions;
 
+#[cfg(feature = "sha512_224")]
+mod sha512_224;
+
+#[cfg(feature = "sha512_224")]
+pub use crate::sha512_224::register_sha512_224_functions;
+
+#[cfg(feature = "sha512_256")]
+mod sha512_256;
+
+#[cfg(feature = "sha512_256")]
+pub use crate::sha512_256::register_sha512_256_functions;
+
+#[cfg(feature = "sha3")]
+mod sha3;
+
+#[cfg(feature = "sha3")]
+pub use crate::sha3::register_sha3_functions;
+
+#[cfg(feature = "keccak")]
+mod keccak;
+
+#[cfg(feature = "keccak")]
+pub use crate::keccak::register_keccak_functions;
+
+#[cfg(feature = "shake")]
+mod shake;
+
+#[cfg(feature = "shake")]
+pub use crate::shake::register_shake_functions;
+
+#[cfg(feature = "kmac")]
+mod kmac;
+
+#[cfg(feature = "kmac")]
+pub use crate::kmac::register_kmac_functions;
+
+#[cfg(feature = "k12")]
+mod k12;
+
+#[cfg(feature = "k12")]
+pub use crate::k12::register_k12_functions;
+
+#[cfg(feature = "turboshake")]
+mod turboshake;
+
+#[cfg(feature = "turboshake")]
+pub use crate::turboshake::register_turboshake_functions;
+
+#[cfg(feature = "ascon")]
+mod ascon;
+
+#[cfg(feature = "ascon")]
+pub use crate::ascon::register_ascon_functions;
+
+#[cfg(feature = "sm3")]
+mod sm3;
+
+#[cfg(feature = "sm3")]
+pub use crate::sm3::register_sm3_functions;
+
+#[cfg(feature = "streebog256")]
+mod streebog256;
+
+#[cfg(feature = "streebog256")]
+pub use crate::streebog256::register_streebog256_functions;
+
+#[cfg(feature = "streebog512")]
+mod streebog512;
+
+#[cfg(feature = "streebog512")]
+pub use crate::streebog512::register_streebog512_functions;
+
+#[cfg(feature = "blake2")]
+mod blake2;
+
+#[cfg(feature = "blake2")]
+pub use crate::blake2::register_blake2_functions;
+
 #[cfg(feature = "blake3")]
 mod blake3;
 
 #[cfg(feature = "blake3")]
 pub use crate::blake3::register_blake3_functions;
 
+#[cfg(feature = "crc")]
+mod crc;
+
+#[cfg(feature = "crc")]
+pub use crate::crc::{register_crc_functions, Adler32, Crc32, Crc32c, Crc64Ecma, Crc64Xz};
+
+#[cfg(feature = "fasthash")]
+mod fasthash;
+
+#[cfg(feature = "fasthash")]
+pub use crate::fasthash::register_fasthash_functions;
+
 #[cfg(feature = "fnv")]
 mod fnv;
 
 #[cfg(feature = "fnv")]
-pub use crate::fnv::register_fnv_functions;
+pub use crate::fnv::{register_fnv_functions, Fnv1_128, Fnv1_32, Fnv1_64, Fnv1a_128, Fnv1a_32};
+
+#[cfg(feature = "highwayhash")]
+mod highwayhash;
+
+#[cfg(feature = "highwayhash")]
+pub use crate::highwayhash::register_highwayhash_functions;
+
+#[cfg(feature = "murmur3")]
+mod murmur3;
+
+#[cfg(feature = "murmur3")]
+pub use crate::murmur3::register_murmur3_functions;
+
+#[cfg(feature = "siphash")]
+mod siphash;
+
+#[cfg(feature = "siphash")]
+pub use crate::siphash::register_siphash_functions;
 
 #[cfg(feature = "xxhash")]
 mod xxhash;
@@ -85,6 +227,66 @@ mod xxhash;
 #[cfg(feature = "xxhash")]
 pub use crate::xxhash::register_xxhash_functions;
 
+#[cfg(feature = "md4")]
+mod md4;
+
+#[cfg(feature = "md4")]
+pub use crate::md4::register_md4_functions;
+
+#[cfg(feature = "ripemd160")]
+mod ripemd160;
+
+#[cfg(feature = "ripemd160")]
+pub use crate::ripemd160::register_ripemd160_functions;
+
+#[cfg(feature = "tiger")]
+mod tiger;
+
+#[cfg(feature = "tiger")]
+pub use crate::tiger::register_tiger_functions;
+
+#[cfg(feature = "whirlpool")]
+mod whirlpool;
+
+#[cfg(feature = "whirlpool")]
+pub use crate::whirlpool::register_whirlpool_functions;
+
+#[cfg(feature = "password")]
+mod password;
+
+#[cfg(feature = "password")]
+pub use crate::password::register_password_functions;
+
+#[cfg(feature = "crypt")]
+mod crypt;
+
+#[cfg(feature = "crypt")]
+pub use crate::crypt::register_crypt_functions;
+
+#[cfg(feature = "kdf")]
+mod kdf;
+
+#[cfg(feature = "kdf")]
+pub use crate::kdf::register_kdf_functions;
+
+#[cfg(feature = "otp")]
+mod otp;
+
+#[cfg(feature = "otp")]
+pub use crate::otp::register_otp_functions;
+
+#[cfg(feature = "jws")]
+mod jws;
+
+#[cfg(feature = "jws")]
+pub use crate::jws::register_jws_functions;
+
+#[cfg(feature = "signature")]
+mod signature;
+
+#[cfg(feature = "signature")]
+pub use crate::signature::register_signature_functions;
+
 /// Register all hashing functions for the given `SQLite` connection.
 /// This is a convenience function that calls all of the `register_*_function` functions.
 /// Features must be enabled for the corresponding functions to be registered.
@@ -121,10 +323,30 @@ pub use crate::xxhash::register_xxhash_functions;
 /// let hash: String = db.query_row("SELECT sha512_hex('hello')", [], |r| r.get(0))?;
 /// assert_eq!(hash, "9B71D224BD62F3785D96D46AD3EA3D73319BFBC2890CAADAE2DFF72519673CA72323C3D99BA5C11D7C7ACC6E14B8C5DA0C4663475C2E5C3ADEF46F73BCDEC043");
 /// # }
+/// # if cfg!(all(feature = "hex", feature = "sha3")) {
+/// let hash: String = db.query_row("SELECT sha3_256_hex('hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, "3338BE694F50C5F338814986CDF0686453A888B84F424D792AF4B9202398F392");
+/// # }
+/// # if cfg!(all(feature = "hex", feature = "keccak")) {
+/// let hash: String = db.query_row("SELECT keccak256_hex('hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, "1C8AFF950685C2ED4BC3174F3472287B56D9517B9C948127319A09A7A36DEAC8");
+/// # }
+/// # if cfg!(all(feature = "hex", feature = "shake")) {
+/// let hash: String = db.query_row("SELECT shake256_hex(16, 'hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, "1234075AE4A1E77316CF2D8000974581");
+/// # }
+/// # if cfg!(all(feature = "hex", feature = "blake2")) {
+/// let hash: String = db.query_row("SELECT blake2b512_hex('hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, "E4CFA39A3D37BE31C59609E807970799CAA68A19BFAA15135F165085E01D41A65BA1E1B146AEB6BD0092B49EAC214C103CCFA3A365954BBBE52F74A2B3620C94");
+/// # }
 /// # if cfg!(all(feature = "hex", feature = "blake3")) {
 /// let hash: String = db.query_row("SELECT blake3_hex('hello')", [], |r| r.get(0))?;
 /// assert_eq!(hash, "EA8F163DB38682925E4491C5E58D4BB3506EF8C14EB78A86E908C5624A67200F");
 /// # }
+/// # if cfg!(all(feature = "hex", feature = "crc")) {
+/// let hash: String = db.query_row("SELECT crc32_hex('hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, "3610A686");
+/// # }
 /// # if cfg!(all(feature = "hex", feature = "fnv")) {
 /// let hash: String = db.query_row("SELECT fnv1a_hex('hello')", [], |r| r.get(0))?;
 /// assert_eq!(hash, "A430D84680AABD0B");
@@ -143,24 +365,114 @@ pub use crate::xxhash::register_xxhash_functions;
 /// # }
 /// ```
 pub fn register_hash_functions(conn: &Connection) -> Result<()> {
+    register_all(conn, DIRECT_ONLY_FLAGS)
+}
+
+/// Register all hashing functions for the given `SQLite` connection, marking them as
+/// [`SQLITE_INNOCUOUS`](https://www.sqlite.org/c3ref/c_deterministic.html) instead of `SQLITE_DIRECTONLY`.
+/// This allows hash functions to be used in triggers, views, `CHECK` constraints,
+/// generated columns, and expression indexes. Registered functions are the same as with [`register_hash_functions`].
+///
+/// Note that a schema using these functions can only be used by connections that register them in the same way.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_innocuous_hash_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_innocuous_hash_functions(&db)?;
+/// # if cfg!(all(feature = "hex", feature = "sha256")) {
+/// db.execute_batch("
+///     CREATE TABLE docs(body TEXT, digest TEXT GENERATED ALWAYS AS (sha256_hex(body)) STORED);
+///     INSERT INTO docs(body) VALUES ('hello');
+/// ")?;
+/// let hash: String = db.query_row("SELECT digest FROM docs", [], |r| r.get(0))?;
+/// assert_eq!(&hash, "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824");
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_innocuous_hash_functions(conn: &Connection) -> Result<()> {
+    register_all(conn, INNOCUOUS_FLAGS)
+}
+
+fn register_all(conn: &Connection, flags: FunctionFlags) -> Result<()> {
     #[cfg(feature = "md5")]
-    register_md5_functions(conn)?;
+    md5::register(conn, flags)?;
     #[cfg(feature = "sha1")]
-    register_sha1_functions(conn)?;
+    sha1::register(conn, flags)?;
     #[cfg(feature = "sha224")]
-    register_sha224_functions(conn)?;
+    sha224::register(conn, flags)?;
     #[cfg(feature = "sha256")]
-    register_sha256_functions(conn)?;
+    sha256::register(conn, flags)?;
     #[cfg(feature = "sha384")]
-    register_sha384_functions(conn)?;
+    sha384::register(conn, flags)?;
     #[cfg(feature = "sha512")]
-    register_sha512_functions(conn)?;
+    sha512::register(conn, flags)?;
+    #[cfg(feature = "sha512_224")]
+    sha512_224::register(conn, flags)?;
+    #[cfg(feature = "sha512_256")]
+    sha512_256::register(conn, flags)?;
+    #[cfg(feature = "sha3")]
+    sha3::register(conn, flags)?;
+    #[cfg(feature = "keccak")]
+    keccak::register(conn, flags)?;
+    #[cfg(feature = "shake")]
+    shake::register(conn, flags)?;
+    #[cfg(feature = "kmac")]
+    kmac::register(conn, flags)?;
+    #[cfg(feature = "k12")]
+    k12::register(conn, flags)?;
+    #[cfg(feature = "turboshake")]
+    turboshake::register(conn, flags)?;
+    #[cfg(feature = "ascon")]
+    ascon::register(conn, flags)?;
+    #[cfg(feature = "sm3")]
+    sm3::register(conn, flags)?;
+    #[cfg(feature = "streebog256")]
+    streebog256::register(conn, flags)?;
+    #[cfg(feature = "streebog512")]
+    streebog512::register(conn, flags)?;
+    #[cfg(feature = "blake2")]
+    blake2::register(conn, flags)?;
     #[cfg(feature = "blake3")]
-    register_blake3_functions(conn)?;
+    blake3::register(conn, flags)?;
+    #[cfg(feature = "crc")]
+    crc::register(conn, flags)?;
+    #[cfg(feature = "fasthash")]
+    fasthash::register(conn, flags)?;
     #[cfg(feature = "fnv")]
-    register_fnv_functions(conn)?;
+    fnv::register(conn, flags)?;
+    #[cfg(feature = "highwayhash")]
+    highwayhash::register(conn, flags)?;
+    #[cfg(feature = "murmur3")]
+    murmur3::register(conn, flags)?;
+    #[cfg(feature = "siphash")]
+    siphash::register(conn, flags)?;
     #[cfg(feature = "xxhash")]
-    register_xxhash_functions(conn)?;
+    xxhash::register(conn, flags)?;
+    #[cfg(feature = "md4")]
+    md4::register(conn, flags)?;
+    #[cfg(feature = "ripemd160")]
+    ripemd160::register(conn, flags)?;
+    #[cfg(feature = "tiger")]
+    tiger::register(conn, flags)?;
+    #[cfg(feature = "whirlpool")]
+    whirlpool::register(conn, flags)?;
+    #[cfg(feature = "password")]
+    password::register(conn, flags)?;
+    #[cfg(feature = "crypt")]
+    crypt::register(conn, flags)?;
+    #[cfg(feature = "kdf")]
+    kdf::register(conn, flags)?;
+    #[cfg(feature = "otp")]
+    otp::register(conn, flags)?;
+    #[cfg(feature = "jws")]
+    jws::register(conn, flags)?;
+    #[cfg(feature = "signature")]
+    signature::register(conn, flags)?;
 
     Ok(())
 }
@@ -0,0 +1,41 @@
+use md4::Md4;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, log_weak_algorithm, DIRECT_ONLY_FLAGS};
+
+/// Register the `md4` SQL function with the given `SQLite` connection.
+/// The function takes a single argument and returns the [MD4 hash](https://en.wikipedia.org/wiki/MD4) (blob) of that argument.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// MD4 is broken, and should only be used to process legacy data, e.g. the Windows NTLM password hash,
+/// which is the MD4 hash of the UTF-16LE encoded password. `SQLite` strings are UTF-8, so pass the
+/// encoded password as a blob. Registering this function logs a notice with the `SQLite` logger.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_md4_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_md4_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT md4('abc')", [], |r| r.get(0))?;
+/// let expected = b"\xa4\x48\x01\x7a\xaf\x21\xd8\x52\x5f\xc1\x0a\xe8\x7a\xa6\x72\x9d";
+/// assert_eq!(hash, expected);
+/// // NTLM hash of "Password"
+/// let hash: Vec<u8> = db.query_row("SELECT md4(x'500061007300730077006F0072006400')", [], |r| r.get(0))?;
+/// let expected = b"\xa4\xf4\x9c\x40\x65\x10\xbd\xca\xb6\x82\x4e\xe7\xc3\x0f\xd8\x52";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_md4_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    log_weak_algorithm("md4");
+    create_hash_fn::<Md4>(conn, "md4", flags)
+}
@@ -1,7 +1,8 @@
 use md5::Md5;
 
+use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::{Connection, Result};
-use crate::scalar::create_hash_fn;
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
 
 /// Register the `md5` SQL function with the given `SQLite` connection.
 /// The function takes a single argument and returns the [MD5 hash](https://en.wikipedia.org/wiki/MD5) (blob) of that argument.
@@ -23,5 +24,9 @@ use crate::scalar::create_hash_fn;
 /// # }
 /// ```
 pub fn register_md5_functions(conn: &Connection) -> Result<()> {
-    create_hash_fn::<Md5>(conn, "md5")
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Md5>(conn, "md5", flags)
 }
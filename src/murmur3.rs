@@ -0,0 +1,195 @@
+use digest::block_buffer::{BlockBuffer, Eager};
+use digest::consts::{U16, U4};
+
+use crate::param::{create_param_hash_fn, create_param_int_hash_fn, ParamDigest, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::DIRECT_ONLY_FLAGS;
+
+/// `MurmurHash3_x86_32`, with the 32-bit seed as the first parameter, and the big-endian output.
+#[derive(Clone)]
+pub(crate) struct Murmur3_32 {
+    h: u32,
+    buffer: BlockBuffer<U4, Eager>,
+    len: u64,
+}
+
+impl Murmur3_32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    fn mix(k: u32) -> u32 {
+        k.wrapping_mul(Self::C1)
+            .rotate_left(15)
+            .wrapping_mul(Self::C2)
+    }
+}
+
+impl ParamDigest for Murmur3_32 {
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        Ok(Self {
+            h: params.u32(0, "seed")?,
+            buffer: BlockBuffer::default(),
+            len: 0,
+        })
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.len += data.len() as u64;
+        let Self { h, buffer, .. } = self;
+        buffer.digest_blocks(data, |blocks| {
+            for block in blocks {
+                *h ^= Self::mix(u32::from_le_bytes(block.0));
+                *h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+            }
+        });
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        // The tail is padded with zeros, which do not change the hash when there is no tail
+        let tail = self.buffer.pad_with_zeros();
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "only the lowest 32 bits of the length are used by the reference implementation"
+        )]
+        let len = self.len as u32;
+        let mut h = self.h ^ Self::mix(u32::from_le_bytes(tail.0)) ^ len;
+        h ^= h >> 16;
+        h = h.wrapping_mul(0x85eb_ca6b);
+        h ^= h >> 13;
+        h = h.wrapping_mul(0xc2b2_ae35);
+        h ^= h >> 16;
+        h.to_be_bytes().to_vec()
+    }
+
+    /// The signed 32-bit hash, as used by Guava, Spark, Cassandra, and Python's `mmh3.hash`.
+    fn to_int(hash: &[u8]) -> i64 {
+        i64::from(hash.iter().fold(0_i32, |acc, v| (acc << 8) | i32::from(*v)))
+    }
+}
+
+/// `MurmurHash3_x64_128`, with the 32-bit seed as the first parameter.
+/// The output is the same as of the reference implementation: `h1`, then `h2`, both little-endian.
+#[derive(Clone)]
+pub(crate) struct Murmur3_128 {
+    h1: u64,
+    h2: u64,
+    buffer: BlockBuffer<U16, Eager>,
+    len: u64,
+}
+
+impl Murmur3_128 {
+    const C1: u64 = 0x87c3_7b91_1142_53d5;
+    const C2: u64 = 0x4cf5_ad43_2745_937f;
+
+    fn mix1(k1: u64) -> u64 {
+        k1.wrapping_mul(Self::C1)
+            .rotate_left(31)
+            .wrapping_mul(Self::C2)
+    }
+
+    fn mix2(k2: u64) -> u64 {
+        k2.wrapping_mul(Self::C2)
+            .rotate_left(33)
+            .wrapping_mul(Self::C1)
+    }
+
+    fn fmix(mut k: u64) -> u64 {
+        k ^= k >> 33;
+        k = k.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        k ^= k >> 33;
+        k = k.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        k ^ (k >> 33)
+    }
+
+    fn split(block: &[u8; 16]) -> (u64, u64) {
+        let (k1, k2) = block.split_at(8);
+        let k1 = k1.iter().rev().fold(0, |acc, v| (acc << 8) | u64::from(*v));
+        let k2 = k2.iter().rev().fold(0, |acc, v| (acc << 8) | u64::from(*v));
+        (k1, k2)
+    }
+}
+
+impl ParamDigest for Murmur3_128 {
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        let seed = u64::from(params.u32(0, "seed")?);
+        Ok(Self {
+            h1: seed,
+            h2: seed,
+            buffer: BlockBuffer::default(),
+            len: 0,
+        })
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.len += data.len() as u64;
+        let Self { h1, h2, buffer, .. } = self;
+        buffer.digest_blocks(data, |blocks| {
+            for block in blocks {
+                let (k1, k2) = Self::split(&block.0);
+                *h1 ^= Self::mix1(k1);
+                *h1 = h1.rotate_left(27).wrapping_add(*h2);
+                *h1 = h1.wrapping_mul(5).wrapping_add(0x52dc_e729);
+                *h2 ^= Self::mix2(k2);
+                *h2 = h2.rotate_left(31).wrapping_add(*h1);
+                *h2 = h2.wrapping_mul(5).wrapping_add(0x3849_5ab5);
+            }
+        });
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        // The tail is padded with zeros, which do not change the hash when there is no tail
+        let (k1, k2) = Self::split(&self.buffer.pad_with_zeros().0);
+        let mut h1 = self.h1 ^ Self::mix1(k1) ^ self.len;
+        let mut h2 = self.h2 ^ Self::mix2(k2) ^ self.len;
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+        h1 = Self::fmix(h1);
+        h2 = Self::fmix(h2);
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+        [h1.to_le_bytes(), h2.to_le_bytes()].concat()
+    }
+}
+
+/// Register the `murmur3_32`, `murmur3_32_int`, and `murmur3_128` SQL functions with the given `SQLite` connection.
+/// The functions compute the [MurmurHash3](https://en.wikipedia.org/wiki/MurmurHash#MurmurHash3) of the argument(s),
+/// with the seed between 0 and 4294967295 as the first argument.
+///
+/// * `murmur3_32(seed, ...)` is the `MurmurHash3_x86_32` variant, returned as the 4-byte big-endian blob.
+/// * `murmur3_32_int(seed, ...)` returns the same hash as a signed 32-bit integer,
+///   the same as Guava, Spark, Cassandra, and Python's `mmh3.hash`.
+/// * `murmur3_128(seed, ...)` is the `MurmurHash3_x64_128` variant, returned as the 16-byte blob
+///   with the same byte order as the reference implementation, Guava, and Python's `mmh3.hash_bytes`.
+///
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_murmur3_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_murmur3_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT murmur3_32(0, 'hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, b"\x24\x8B\xFA\x47");
+/// let hash: i64 = db.query_row("SELECT murmur3_32_int(0, 'hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, 0x248B_FA47);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_murmur3_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_param_hash_fn::<Murmur3_32>(conn, "murmur3_32", flags)?;
+    create_param_int_hash_fn::<Murmur3_32>(conn, "murmur3_32", flags)?;
+    create_param_hash_fn::<Murmur3_128>(conn, "murmur3_128", flags)
+}
@@ -0,0 +1,290 @@
+use std::ops::RangeInclusive;
+
+use digest::common::BlockSizeUser;
+use digest::Digest;
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+
+use crate::param::{ct_eq, has_args, Hmac, Params};
+use crate::rusqlite::functions::{Context, FunctionFlags};
+use crate::rusqlite::types::ValueRef;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_scalar_function, DIRECT_ONLY_FLAGS};
+
+/// Alphabet of the RFC 4648 base32 encoding, used by the `otpauth://` URIs.
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const DEFAULT_DIGITS: usize = 6;
+/// RFC 4226 requires at least 6 digits, and the 31-bit truncated HMAC value has enough entropy for 8 digits.
+const DIGITS: RangeInclusive<usize> = 6..=8;
+const DEFAULT_STEP: usize = 30;
+/// The time step is at most a day.
+const STEP: RangeInclusive<usize> = 1..=86400;
+const DEFAULT_WINDOW: usize = 1;
+/// The number of time steps to accept before and after the current one, to allow for clock drift.
+const WINDOW: RangeInclusive<usize> = 0..=10;
+
+/// The HMAC algorithms allowed by RFC 6238.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Algorithm {
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl Algorithm {
+    const ALL: [Self; 3] = [Self::Sha1, Self::Sha256, Self::Sha512];
+    const NAMES: &'static str = "sha1, sha256, or sha512";
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Sha1 => "sha1",
+            Self::Sha256 => "sha256",
+            Self::Sha512 => "sha512",
+        }
+    }
+
+    /// Get the optional algorithm parameter, using SHA-1 for `NULL`.
+    fn from_param(params: &Params, idx: usize) -> Result<Self> {
+        if !params.is_set(idx) {
+            return Ok(Self::Sha1);
+        }
+        let name = params.text(idx, "algo")?;
+        Self::ALL
+            .into_iter()
+            .find(|v| v.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                params.error(
+                    idx,
+                    "algo",
+                    format_args!("must be one of {}, got {name}", Self::NAMES),
+                )
+            })
+    }
+
+    fn hmac(self, key: &[u8], data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Sha1 => hmac::<Sha1>(key, data),
+            Self::Sha256 => hmac::<Sha256>(key, data),
+            Self::Sha512 => hmac::<Sha512>(key, data),
+        }
+    }
+}
+
+fn hmac<D: Digest + BlockSizeUser>(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<D>::with_block_size(key, D::block_size());
+    mac.update(data);
+    mac.finalize()
+}
+
+/// The HOTP value from [RFC 4226](https://www.rfc-editor.org/rfc/rfc4226.html), as text with `digits` digits.
+fn hotp(secret: &[u8], counter: u64, digits: usize, algo: Algorithm) -> String {
+    let hash = algo.hmac(secret, &counter.to_be_bytes());
+    let offset = usize::from(hash[hash.len() - 1] & 0x0F);
+    let value = u32::from_be_bytes([
+        hash[offset],
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    // The last digits of the zero-padded 31-bit value are the same as the value modulo 10^digits
+    let code = format!("{:010}", value & 0x7FFF_FFFF);
+    code[code.len() - digits..].to_string()
+}
+
+/// Encode bytes as uppercase RFC 4648 base32 without the `=` padding, as used by the `otpauth://` URIs.
+fn base32_encode(data: &[u8]) -> String {
+    let mut result = String::with_capacity(data.len().div_ceil(5) * 8);
+    let mut buffer = 0_u16;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | u16::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(char::from(
+                BASE32_ALPHABET[usize::from((buffer >> bits) & 0x1F)],
+            ));
+        }
+        buffer &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        result.push(char::from(
+            BASE32_ALPHABET[usize::from((buffer << (5 - bits)) & 0x1F)],
+        ));
+    }
+    result
+}
+
+/// Decode case-insensitive RFC 4648 base32, ignoring spaces and the trailing `=` padding.
+/// Returns `None` if the text contains any other characters.
+fn base32_decode(text: &[u8]) -> Option<Vec<u8>> {
+    let len = text.iter().rposition(|&c| c != b'=').map_or(0, |p| p + 1);
+    let data = &text[..len];
+    let mut result = Vec::with_capacity(data.len() * 5 / 8);
+    let mut buffer = 0_u16;
+    let mut bits = 0;
+    for &ch in data {
+        let value = match ch.to_ascii_uppercase() {
+            c @ b'A'..=b'Z' => c - b'A',
+            c @ b'2'..=b'7' => c - b'2' + 26,
+            b' ' => continue,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | u16::from(value);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits).to_be_bytes()[1]);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(result)
+}
+
+/// Get the secret, decoding the text values as base32, and using the blobs as is.
+fn secret(ctx: &Context, params: &Params, idx: usize) -> Result<Vec<u8>> {
+    let value = params.bytes(idx, "secret")?;
+    let secret = if matches!(ctx.get_raw(idx), ValueRef::Text(_)) {
+        base32_decode(value)
+            .ok_or_else(|| params.error(idx, "secret", "must be a valid base32 string"))?
+    } else {
+        value.to_vec()
+    };
+    if secret.is_empty() {
+        return Err(params.error(idx, "secret", "must not be empty"));
+    }
+    Ok(secret)
+}
+
+/// Get a non-negative integer parameter, e.g. a counter or a timestamp.
+fn unsigned(params: &Params, idx: usize, name: &str) -> Result<u64> {
+    let value = params.int(idx, name)?;
+    u64::try_from(value)
+        .map_err(|_| params.error(idx, name, format_args!("must not be negative, got {value}")))
+}
+
+/// Get an optional parameter within the given range, using `default` for `NULL`.
+fn optional(
+    params: &Params,
+    idx: usize,
+    name: &str,
+    range: RangeInclusive<usize>,
+    default: usize,
+) -> Result<usize> {
+    if params.is_set(idx) {
+        params.length(idx, name, range)
+    } else {
+        Ok(default)
+    }
+}
+
+/// Register the `hotp`, `totp`, `totp_verify`, `base32_encode`, and `base32_decode` SQL functions
+/// with the given `SQLite` connection.
+///
+/// * `hotp(secret, counter [, digits [, algo]])` returns the counter-based one-time password
+///   from [RFC 4226](https://www.rfc-editor.org/rfc/rfc4226.html) as text, e.g. `'094287'`.
+/// * `totp(secret, unix_time [, step [, digits [, algo]]])` returns the time-based one-time password
+///   from [RFC 6238](https://www.rfc-editor.org/rfc/rfc6238.html) for the given Unix time in seconds.
+/// * `totp_verify(secret, code, unix_time [, window [, step [, digits [, algo]]]])` returns `1` if the code
+///   is the `totp` code with the same `step`, `digits`, and `algo`, allowing `window` steps of clock drift
+///   in each direction (1 by default).
+/// * `base32_encode(data)` and `base32_decode(text)` convert secrets to and from the base32 text,
+///   as used by the `otpauth://` URIs.
+///
+/// A text `secret` is decoded as base32, e.g. `'JBSWY3DPEHPK3PXP'`, and a blob `secret` is used as is.
+/// The `digits` are between 6 and 8 (6 by default), the `step` is between 1 and 86400 seconds (30 by default),
+/// the `window` is between 0 and 10, and the `algo` is `sha1` (default), `sha256`, or `sha512`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_otp_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_otp_functions(&db)?;
+/// let code: String = db.query_row("SELECT totp(x'3132333435363738393031323334353637383930', 59, 30, 8)", [], |r| r.get(0))?;
+/// assert_eq!(code, "94287082");
+/// let valid: bool = db.query_row("SELECT totp_verify('JBSWY3DPEHPK3PXP', totp('JBSWY3DPEHPK3PXP', 1700000000), 1700000020)", [], |r| r.get(0))?;
+/// assert!(valid);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_otp_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_scalar_function(conn, "hotp", flags, |ctx| {
+        if !has_args(ctx, 2, 4)? {
+            return Ok(None);
+        }
+        let params = Params::new("hotp", ctx, ctx.len());
+        let secret = secret(ctx, &params, 0)?;
+        let counter = unsigned(&params, 1, "counter")?;
+        let digits = optional(&params, 2, "digits", DIGITS, DEFAULT_DIGITS)?;
+        let algo = Algorithm::from_param(&params, 3)?;
+        Ok(Some(hotp(&secret, counter, digits, algo)))
+    })?;
+
+    create_scalar_function(conn, "totp", flags, |ctx| {
+        if !has_args(ctx, 2, 5)? {
+            return Ok(None);
+        }
+        let params = Params::new("totp", ctx, ctx.len());
+        let secret = secret(ctx, &params, 0)?;
+        let time = unsigned(&params, 1, "unix_time")?;
+        let step = optional(&params, 2, "step", STEP, DEFAULT_STEP)?;
+        let digits = optional(&params, 3, "digits", DIGITS, DEFAULT_DIGITS)?;
+        let algo = Algorithm::from_param(&params, 4)?;
+        Ok(Some(hotp(&secret, time / step as u64, digits, algo)))
+    })?;
+
+    create_scalar_function(conn, "totp_verify", flags, |ctx| {
+        if !has_args(ctx, 3, 7)? {
+            return Ok(None);
+        }
+        let params = Params::new("totp_verify", ctx, ctx.len());
+        let secret = secret(ctx, &params, 0)?;
+        let code = params.text(1, "code")?;
+        let time = unsigned(&params, 2, "unix_time")?;
+        let window = optional(&params, 3, "window", WINDOW, DEFAULT_WINDOW)? as u64;
+        let step = optional(&params, 4, "step", STEP, DEFAULT_STEP)?;
+        let digits = optional(&params, 5, "digits", DIGITS, DEFAULT_DIGITS)?;
+        let algo = Algorithm::from_param(&params, 6)?;
+        if code.len() != digits || !code.bytes().all(|c| c.is_ascii_digit()) {
+            return Ok(Some(false));
+        }
+        let counter = time / step as u64;
+        // Compare all bytes of every step in the window, so the time taken does not reveal the matching code
+        let valid = (counter.saturating_sub(window)..=counter.saturating_add(window)).fold(
+            false,
+            |found, step| {
+                let expected = hotp(&secret, step, digits, algo);
+                ct_eq(expected.as_bytes(), code.as_bytes()) || found
+            },
+        );
+        Ok(Some(valid))
+    })?;
+
+    create_scalar_function(conn, "base32_encode", flags, |ctx| {
+        if !has_args(ctx, 1, 1)? {
+            return Ok(None);
+        }
+        let params = Params::new("base32_encode", ctx, 1);
+        Ok(Some(base32_encode(params.bytes(0, "data")?)))
+    })?;
+
+    create_scalar_function(conn, "base32_decode", flags, |ctx| {
+        if !has_args(ctx, 1, 1)? {
+            return Ok(None);
+        }
+        let params = Params::new("base32_decode", ctx, 1);
+        let text = params.bytes(0, "text")?;
+        base32_decode(text)
+            .map(Some)
+            .ok_or_else(|| params.error(0, "text", "must be a valid base32 string"))
+    })?;
+
+    Ok(())
+}
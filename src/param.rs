@@ -0,0 +1,534 @@
+use std::ops::RangeInclusive;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+use digest::Digest;
+#[cfg(any(feature = "ascon", feature = "blake3", feature = "shake"))]
+use digest::ExtendableOutput;
+#[cfg(feature = "hex")]
+use hex::ToHex as _;
+#[cfg(feature = "trace")]
+use log::trace;
+#[cfg(feature = "aggregate")]
+use rusqlite::functions::Aggregate;
+use rusqlite::functions::Context;
+#[cfg(feature = "aggregate")]
+use rusqlite::ToSql;
+
+#[cfg(feature = "aggregate")]
+use crate::aggregate::create_agg_function;
+use crate::builder::Variants;
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::types::{Type, ValueRef};
+use crate::rusqlite::Error::{InvalidFunctionParameterType, InvalidParameterCount};
+use crate::rusqlite::{Connection, Error, Result};
+use crate::scalar::create_scalar_function;
+use crate::scalar::NamedDigest;
+#[cfg(any(feature = "fasthash", feature = "murmur3", feature = "siphash"))]
+use crate::state::to_int;
+
+#[cfg(not(feature = "trace"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {};
+}
+
+/// The largest output length accepted by the variable-length functions like `shake128(len, ...)`.
+#[allow(dead_code, reason = "not used by every feature combination")]
+pub(crate) const MAX_OUTPUT_LEN: usize = 1024 * 1024;
+
+/// A hasher created from the leading SQL arguments like an output length, a key, or a seed.
+/// All other arguments are hashed the same way as with the [`NamedDigest`](crate::NamedDigest) functions.
+pub(crate) trait ParamDigest: Sized + Clone + UnwindSafe + RefUnwindSafe + 'static {
+    /// Number of leading arguments passed to [`ParamDigest::new`].
+    const PARAMS: usize;
+
+    fn new(params: &Params) -> Result<Self>;
+
+    fn update(&mut self, data: &[u8]);
+
+    fn finalize(self) -> Vec<u8>;
+
+    /// Convert the finalized hash into the result of the `_int` functions.
+    /// By default, the hash is read as a big-endian unsigned integer.
+    #[cfg(any(feature = "fasthash", feature = "murmur3", feature = "siphash"))]
+    fn to_int(hash: &[u8]) -> i64 {
+        to_int(hash)
+    }
+}
+
+/// Leading arguments of a function call, with helpers to validate them.
+pub(crate) struct Params<'a> {
+    fn_name: &'a str,
+    values: Vec<ValueRef<'a>>,
+}
+
+#[allow(
+    dead_code,
+    reason = "not every helper is used by every feature combination"
+)]
+impl<'a> Params<'a> {
+    pub fn new(fn_name: &'a str, ctx: &'a Context<'_>, count: usize) -> Self {
+        Self {
+            fn_name,
+            values: (0..count).map(|idx| ctx.get_raw(idx)).collect(),
+        }
+    }
+
+    /// Create a user-facing error for the parameter at `idx`.
+    pub fn error(&self, idx: usize, name: &str, msg: impl std::fmt::Display) -> Error {
+        Error::UserFunctionError(
+            format!("{}: argument {} ({name}) {msg}", self.fn_name, idx + 1).into(),
+        )
+    }
+
+    /// Returns `true` if the optional parameter at `idx` was given, and is not `NULL`.
+    pub fn is_set(&self, idx: usize) -> bool {
+        !matches!(self.values.get(idx), None | Some(ValueRef::Null))
+    }
+
+    /// Get a text or blob parameter as bytes.
+    pub fn bytes(&self, idx: usize, name: &str) -> Result<&'a [u8]> {
+        match self.values[idx] {
+            ValueRef::Blob(val) | ValueRef::Text(val) => Ok(val),
+            ValueRef::Null => Err(self.error(idx, name, "must not be NULL")),
+            ValueRef::Integer(_) => Err(InvalidFunctionParameterType(idx, Type::Integer)),
+            ValueRef::Real(_) => Err(InvalidFunctionParameterType(idx, Type::Real)),
+        }
+    }
+
+    /// Get a text or blob parameter that must not be longer than `max` bytes.
+    pub fn max_bytes(&self, idx: usize, name: &str, max: usize) -> Result<&'a [u8]> {
+        let val = self.bytes(idx, name)?;
+        if val.len() > max {
+            return Err(self.error(
+                idx,
+                name,
+                format_args!("must be at most {max} bytes, got {}", val.len()),
+            ));
+        }
+        Ok(val)
+    }
+
+    /// Get a text or blob parameter that must be exactly `N` bytes long, e.g. a key.
+    pub fn key<const N: usize>(&self, idx: usize, name: &str) -> Result<&'a [u8; N]> {
+        let val = self.bytes(idx, name)?;
+        val.try_into().map_err(|_| {
+            self.error(
+                idx,
+                name,
+                format_args!("must be exactly {N} bytes, got {}", val.len()),
+            )
+        })
+    }
+
+    /// Get a text or blob parameter that must be a valid UTF-8 string.
+    pub fn text(&self, idx: usize, name: &str) -> Result<&'a str> {
+        let val = self.bytes(idx, name)?;
+        std::str::from_utf8(val).map_err(|_| self.error(idx, name, "must be a valid UTF-8 string"))
+    }
+
+    /// Get an integer parameter.
+    pub fn int(&self, idx: usize, name: &str) -> Result<i64> {
+        match self.values[idx] {
+            ValueRef::Integer(val) => Ok(val),
+            ValueRef::Null => Err(self.error(idx, name, "must not be NULL")),
+            ValueRef::Blob(_) => Err(InvalidFunctionParameterType(idx, Type::Blob)),
+            ValueRef::Text(_) => Err(InvalidFunctionParameterType(idx, Type::Text)),
+            ValueRef::Real(_) => Err(InvalidFunctionParameterType(idx, Type::Real)),
+        }
+    }
+
+    /// Get an integer parameter that must fit into 32 bits, e.g. a seed.
+    pub fn u32(&self, idx: usize, name: &str) -> Result<u32> {
+        let val = self.int(idx, name)?;
+        u32::try_from(val).map_err(|_| {
+            self.error(
+                idx,
+                name,
+                format_args!("must be between 0 and {}, got {val}", u32::MAX),
+            )
+        })
+    }
+
+    /// Get a 64-bit integer parameter, e.g. a seed, using the two's complement bits of the negative values.
+    pub fn u64(&self, idx: usize, name: &str) -> Result<u64> {
+        Ok(u64::from_ne_bytes(self.int(idx, name)?.to_ne_bytes()))
+    }
+
+    /// Get an integer parameter that must be within the given range.
+    pub fn length(&self, idx: usize, name: &str, range: RangeInclusive<usize>) -> Result<usize> {
+        let val = self.int(idx, name)?;
+        usize::try_from(val)
+            .ok()
+            .filter(|v| range.contains(v))
+            .ok_or_else(|| {
+                self.error(
+                    idx,
+                    name,
+                    format_args!(
+                        "must be between {} and {}, got {val}",
+                        range.start(),
+                        range.end()
+                    ),
+                )
+            })
+    }
+}
+
+/// Any extendable-output function, with the output length as the first parameter.
+#[cfg(any(feature = "ascon", feature = "blake3", feature = "shake"))]
+#[derive(Clone)]
+pub(crate) struct XofLen<T> {
+    hasher: T,
+    len: usize,
+}
+
+#[cfg(any(feature = "ascon", feature = "blake3", feature = "shake"))]
+impl<T> ParamDigest for XofLen<T>
+where
+    T: ExtendableOutput + Default + Clone + UnwindSafe + RefUnwindSafe + 'static,
+{
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        Ok(Self {
+            hasher: T::default(),
+            len: params.length(0, "len", 1..=MAX_OUTPUT_LEN)?,
+        })
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        let mut output = vec![0; self.len];
+        self.hasher.finalize_xof_into(&mut output);
+        output
+    }
+}
+
+/// Any [`NamedDigest`] with the output truncated to the length given as the first parameter.
+/// The length must be between 1 and the full digest size, e.g. `sha256_trunc(len, ...)`.
+#[derive(Clone)]
+pub(crate) struct Truncated<T> {
+    hasher: T,
+    len: usize,
+}
+
+impl<T> ParamDigest for Truncated<T>
+where
+    T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static,
+{
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        Ok(Self {
+            hasher: T::new(),
+            len: params.length(0, "len", 1..=<T as Digest>::output_size())?,
+        })
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        let mut output = self.hasher.finalize().to_vec();
+        output.truncate(self.len);
+        output
+    }
+}
+
+/// [HMAC](https://www.rfc-editor.org/rfc/rfc2104.html) of any [`NamedDigest`] with a block size,
+/// with the key as the first parameter.
+#[cfg(any(feature = "hmac", feature = "jws", feature = "kdf", feature = "otp"))]
+#[derive(Clone)]
+#[allow(dead_code, reason = "not used by every feature combination")]
+pub(crate) struct Hmac<T> {
+    inner: T,
+    outer: T,
+}
+
+#[cfg(any(feature = "hmac", feature = "jws", feature = "kdf", feature = "otp"))]
+#[allow(dead_code, reason = "not used by every feature combination")]
+impl<T: Digest> Hmac<T> {
+    /// Create the HMAC state for the given key and the internal block size of the digest.
+    pub fn with_block_size(key: &[u8], block_size: usize) -> Self {
+        // Keys longer than the block size are hashed first, shorter ones are padded with zeros
+        let mut block = if key.len() > block_size {
+            T::digest(key).to_vec()
+        } else {
+            key.to_vec()
+        };
+        block.resize(block_size, 0);
+        let mut inner = T::new();
+        inner.update(block.iter().map(|v| v ^ 0x36).collect::<Vec<u8>>());
+        let mut outer = T::new();
+        outer.update(block.iter().map(|v| v ^ 0x5C).collect::<Vec<u8>>());
+        Self { inner, outer }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        let mut outer = self.outer;
+        outer.update(self.inner.finalize());
+        outer.finalize().to_vec()
+    }
+}
+
+#[cfg(any(feature = "hmac", feature = "kdf"))]
+#[allow(dead_code, reason = "not used by every feature combination")]
+impl<T: NamedDigest> Hmac<T> {
+    /// Create the HMAC state for the given key, or `None` if the digest has no block size.
+    pub fn with_key(key: &[u8]) -> Option<Self> {
+        Some(Self::with_block_size(key, T::block_size()?))
+    }
+}
+
+#[cfg(any(feature = "hmac", feature = "kdf"))]
+impl<T> ParamDigest for Hmac<T>
+where
+    T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static,
+{
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        let key = params.bytes(0, "key")?;
+        Self::with_key(key).ok_or_else(|| params.error(0, "key", "is not supported by this digest"))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        Self::update(self, data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        Self::finalize(self)
+    }
+}
+
+/// Make sure a function got between `min` and `max` arguments.
+/// Returns `false` if any of the first `min` arguments is `NULL`, so the function should return `NULL`.
+#[allow(dead_code, reason = "not used by every feature combination")]
+pub(crate) fn has_args(ctx: &Context, min: usize, max: usize) -> Result<bool> {
+    let count = ctx.len();
+    if !(min..=max).contains(&count) {
+        return Err(InvalidParameterCount(count, min));
+    }
+    Ok((0..min).all(|idx| !matches!(ctx.get_raw(idx), ValueRef::Null)))
+}
+
+/// Compare two byte strings without revealing the position of the first difference through timing.
+#[cfg(any(feature = "crypt", feature = "jws", feature = "otp"))]
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len()
+        && std::hint::black_box(a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y))) == 0
+}
+
+/// Same as [`HashState`](crate::HashState), but the hasher is created from the leading parameters.
+#[derive(Debug)]
+pub(crate) struct ParamState<T> {
+    hasher: Option<T>,
+    has_values: bool,
+}
+
+impl<T> Default for ParamState<T> {
+    fn default() -> Self {
+        Self {
+            hasher: None,
+            has_values: false,
+        }
+    }
+}
+
+impl<T: ParamDigest> ParamState<T> {
+    /// Parse the leading parameters, and add all remaining arguments to the hash.
+    /// The parameters are only used when the hasher is created, i.e. for the first row of an aggregate.
+    pub fn add_args(&mut self, fn_name: &str, ctx: &Context) -> Result<()> {
+        let param_count = ctx.len();
+        if param_count <= T::PARAMS {
+            return Err(InvalidParameterCount(param_count, T::PARAMS + 1));
+        }
+        let hasher = match &mut self.hasher {
+            Some(hasher) => hasher,
+            None => self
+                .hasher
+                .insert(T::new(&Params::new(fn_name, ctx, T::PARAMS))?),
+        };
+        for idx in T::PARAMS..param_count {
+            match ctx.get_raw(idx) {
+                ValueRef::Blob(val) => {
+                    trace!("{fn_name}: hashing blob arg{idx}={val:?}");
+                    hasher.update(val);
+                    self.has_values = true;
+                }
+                ValueRef::Text(val) => {
+                    trace!("{fn_name}: hashing text arg{idx}={val:?}");
+                    hasher.update(val);
+                    self.has_values = true;
+                }
+                ValueRef::Null => {
+                    trace!("{fn_name}: ignoring arg{idx}=NULL");
+                }
+                ValueRef::Integer(_) => Err(InvalidFunctionParameterType(idx, Type::Integer))?,
+                ValueRef::Real(_) => Err(InvalidFunctionParameterType(idx, Type::Real))?,
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn finalize(self) -> Option<Vec<u8>> {
+        match self.hasher {
+            Some(hasher) if self.has_values => Some(hasher.finalize()),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    #[cfg(any(feature = "fasthash", feature = "murmur3", feature = "siphash"))]
+    pub fn finalize_int(self) -> Option<i64> {
+        self.finalize().map(|hash| T::to_int(&hash))
+    }
+
+    #[inline]
+    #[cfg(feature = "hex")]
+    pub fn finalize_hex(self) -> Option<String> {
+        match self.hasher {
+            None => None,
+            Some(_) if !self.has_values => Some(String::new()),
+            Some(hasher) => Some(hasher.finalize().encode_hex_upper()),
+        }
+    }
+}
+
+/// Register `fn_name`, `fn_name_hex`, `fn_name_concat`, and `fn_name_concat_hex` functions
+/// for a hasher that takes [`ParamDigest::PARAMS`] leading arguments.
+#[allow(dead_code, reason = "not used by every feature combination")]
+pub(crate) fn create_param_hash_fn<T: ParamDigest>(
+    conn: &Connection,
+    fn_name: &str,
+    flags: FunctionFlags,
+) -> Result<()> {
+    create_param_variants::<T>(conn, fn_name, Variants::ALL, flags)
+}
+
+/// Register the requested `variants` of a hasher that takes [`ParamDigest::PARAMS`] leading arguments.
+pub(crate) fn create_param_variants<T: ParamDigest>(
+    conn: &Connection,
+    fn_name: &str,
+    variants: Variants,
+    flags: FunctionFlags,
+) -> Result<()> {
+    if variants.contains(Variants::SCALAR) {
+        let name = fn_name.to_string();
+        create_scalar_function(conn, fn_name, flags, move |c| {
+            let mut state = ParamState::<T>::default();
+            state.add_args(&name, c)?;
+            Ok(state.finalize())
+        })?;
+    }
+
+    #[cfg(feature = "hex")]
+    if variants.contains(Variants::HEX) {
+        let name = fn_name.to_string();
+        create_scalar_function(conn, &format!("{fn_name}_hex"), flags, move |c| {
+            let mut state = ParamState::<T>::default();
+            state.add_args(&name, c)?;
+            Ok(state.finalize_hex())
+        })?;
+    }
+
+    #[cfg(feature = "aggregate")]
+    if variants.contains(Variants::CONCAT) {
+        let fn_name = format!("{fn_name}_concat");
+        create_agg_function(
+            conn,
+            &fn_name,
+            flags,
+            ParamAggType::<T, Vec<u8>>::new(&fn_name, ParamState::finalize),
+        )?;
+    }
+
+    #[cfg(all(feature = "aggregate", feature = "hex"))]
+    if variants.contains(Variants::CONCAT_HEX) {
+        let fn_name = format!("{fn_name}_concat_hex");
+        create_agg_function(
+            conn,
+            &fn_name,
+            flags,
+            ParamAggType::<T, String>::new(&fn_name, ParamState::finalize_hex),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Register `fn_name_int` and `fn_name_int_concat` functions returning the `T` hash as an integer.
+/// The hash must not be longer than 8 bytes.
+#[cfg(any(feature = "fasthash", feature = "murmur3", feature = "siphash"))]
+pub(crate) fn create_param_int_hash_fn<T: ParamDigest>(
+    conn: &Connection,
+    fn_name: &str,
+    flags: FunctionFlags,
+) -> Result<()> {
+    let int_name = format!("{fn_name}_int");
+    let name = int_name.clone();
+    create_scalar_function(conn, &int_name, flags, move |c| {
+        let mut state = ParamState::<T>::default();
+        state.add_args(&name, c)?;
+        Ok(state.finalize_int())
+    })?;
+
+    #[cfg(feature = "aggregate")]
+    {
+        let fn_name = format!("{fn_name}_int_concat");
+        create_agg_function(
+            conn,
+            &fn_name,
+            flags,
+            ParamAggType::<T, i64>::new(&fn_name, ParamState::finalize_int),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Aggregate for [`ParamDigest`] hashers. The leading parameters are taken from the first row.
+#[cfg(feature = "aggregate")]
+struct ParamAggType<D, R> {
+    fn_name: String,
+    to_final: fn(ParamState<D>) -> Option<R>,
+}
+
+#[cfg(feature = "aggregate")]
+impl<D: ParamDigest, R> ParamAggType<D, R> {
+    fn new(fn_name: &str, to_final: fn(ParamState<D>) -> Option<R>) -> Self {
+        Self {
+            fn_name: fn_name.to_string(),
+            to_final,
+        }
+    }
+}
+
+#[cfg(feature = "aggregate")]
+impl<T: ParamDigest, R: ToSql> Aggregate<ParamState<T>, Option<R>> for ParamAggType<T, R> {
+    fn init(&self, _: &mut Context<'_>) -> Result<ParamState<T>> {
+        trace!("{}: Aggregate::init", self.fn_name);
+        Ok(ParamState::default())
+    }
+
+    fn step(&self, ctx: &mut Context<'_>, agg: &mut ParamState<T>) -> Result<()> {
+        agg.add_args(&self.fn_name, ctx)
+    }
+
+    fn finalize(&self, _: &mut Context<'_>, agg: Option<ParamState<T>>) -> Result<Option<R>> {
+        trace!("{}: Aggregate::finalize", self.fn_name);
+        match agg {
+            Some(agg) => Ok((self.to_final)(agg)),
+            None => Ok(None),
+        }
+    }
+}
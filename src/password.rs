@@ -0,0 +1,318 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr as _;
+
+use argon2::Argon2;
+use password_hash::phc::PasswordHash;
+use password_hash::{PasswordHasher as _, PasswordVerifier};
+use scrypt::Scrypt;
+
+use crate::param::{has_args, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::Error::UserFunctionError;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_scalar_function, DIRECT_ONLY_FLAGS, PASSWORD_FLAGS};
+
+/// The longest password accepted by all password functions.
+const MAX_PASSWORD_LEN: usize = 4096;
+/// Argon2id memory cost limit in KiB, i.e. 1 GiB.
+const ARGON2_MAX_M_COST: u32 = 1024 * 1024;
+const ARGON2_MAX_T_COST: u32 = 16;
+/// Parallelism limit shared by Argon2id and scrypt.
+const MAX_P_COST: u32 = 16;
+const BCRYPT_MAX_COST: u32 = 16;
+const SCRYPT_MAX_LOG_N: u32 = 20;
+const SCRYPT_MAX_R: u32 = 32;
+/// Scrypt memory limit in bytes, i.e. 1 GiB.
+const SCRYPT_MAX_MEMORY: u64 = 1 << 30;
+
+/// A password hashing algorithm together with its cost parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Policy {
+    Argon2id { m: u32, t: u32, p: u32 },
+    Bcrypt { cost: u32 },
+    Scrypt { ln: u32, r: u32, p: u32 },
+}
+
+impl Policy {
+    /// Create a policy for the algorithm name with the OWASP recommended parameters.
+    fn new(algo: &str) -> Result<Self, String> {
+        Ok(match algo.to_ascii_lowercase().as_str() {
+            "argon2id" => Self::Argon2id {
+                m: 19 * 1024,
+                t: 2,
+                p: 1,
+            },
+            "bcrypt" => Self::Bcrypt { cost: 12 },
+            "scrypt" => Self::Scrypt { ln: 17, r: 8, p: 1 },
+            _ => {
+                return Err(format!(
+                    "must be one of argon2id, bcrypt, or scrypt, got {algo}"
+                ))
+            }
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Argon2id { .. } => "argon2id",
+            Self::Bcrypt { .. } => "bcrypt",
+            Self::Scrypt { .. } => "scrypt",
+        }
+    }
+
+    /// Override the parameters with a comma-separated list like `m=19456,t=2,p=1`.
+    fn with_params(mut self, params: Option<&str>) -> Result<Self, String> {
+        let algo = self.name();
+        for param in params.unwrap_or_default().split(',') {
+            let param = param.trim();
+            if param.is_empty() {
+                continue;
+            }
+            let (key, value) = param
+                .split_once('=')
+                .ok_or_else(|| format!("must be a list of key=value pairs, got {param}"))?;
+            let value = value
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| format!("{key} must be a non-negative integer, got {value}"))?;
+            let target = match (&mut self, key.trim()) {
+                (Self::Argon2id { m, .. }, "m") => m,
+                (Self::Argon2id { t, .. }, "t") | (Self::Bcrypt { cost: t }, "cost") => t,
+                (Self::Argon2id { p, .. } | Self::Scrypt { p, .. }, "p") => p,
+                (Self::Scrypt { ln, .. }, "ln") => ln,
+                (Self::Scrypt { r, .. }, "r") => r,
+                (_, key) => return Err(format!("{key} is not a valid {algo} parameter")),
+            };
+            *target = value;
+        }
+        self.check()?;
+        Ok(self)
+    }
+
+    /// Get the policy of a stored bcrypt hash or PHC string, making sure it is within the cost limits.
+    fn from_hash(phc: &str) -> Result<Self, String> {
+        let policy = if phc.starts_with("$2") {
+            let parts = bcrypt::HashParts::from_str(phc).map_err(|e| e.to_string())?;
+            Self::Bcrypt {
+                cost: parts.get_cost(),
+            }
+        } else {
+            let hash = PasswordHash::new(phc).map_err(|e| e.to_string())?;
+            match hash.algorithm.as_str() {
+                "argon2id" => {
+                    let params = argon2::Params::try_from(&hash).map_err(|e| e.to_string())?;
+                    Self::Argon2id {
+                        m: params.m_cost(),
+                        t: params.t_cost(),
+                        p: params.p_cost(),
+                    }
+                }
+                "scrypt" => {
+                    let params = scrypt::Params::try_from(&hash).map_err(|e| e.to_string())?;
+                    Self::Scrypt {
+                        ln: params.log_n().into(),
+                        r: params.r(),
+                        p: params.p(),
+                    }
+                }
+                algo => return Err(format!("uses unsupported algorithm {algo}")),
+            }
+        };
+        policy.check()?;
+        Ok(policy)
+    }
+
+    /// Make sure the parameters are valid, and that they do not exceed the cost limits.
+    fn check(self) -> Result<(), String> {
+        match self {
+            Self::Argon2id { m, t, p } => {
+                check_range("p", p, 1..=MAX_P_COST)?;
+                check_range("t", t, 1..=ARGON2_MAX_T_COST)?;
+                check_range("m", m, 8 * p..=ARGON2_MAX_M_COST)
+            }
+            Self::Bcrypt { cost } => check_range("cost", cost, 4..=BCRYPT_MAX_COST),
+            Self::Scrypt { ln, r, p } => {
+                check_range("ln", ln, 1..=SCRYPT_MAX_LOG_N)?;
+                check_range("r", r, 1..=SCRYPT_MAX_R)?;
+                check_range("p", p, 1..=MAX_P_COST)?;
+                let memory = 128 * u64::from(r) * (1 << ln);
+                if memory > SCRYPT_MAX_MEMORY {
+                    return Err(format!(
+                        "ln={ln},r={r} requires {memory} bytes of memory, at most {SCRYPT_MAX_MEMORY} is allowed"
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Hash the password with a random salt from the OS CSPRNG.
+    fn hash(self, password: &[u8]) -> Result<String, String> {
+        let salt = password_hash::try_generate_salt()
+            .map_err(|e| format!("failed to generate a salt: {e}"))?;
+        match self {
+            Self::Argon2id { m, t, p } => {
+                let params = argon2::Params::new(m, t, p, None).map_err(|e| e.to_string())?;
+                Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+                    .hash_password_with_salt(password, &salt)
+                    .map(|hash| hash.to_string())
+                    .map_err(|e| e.to_string())
+            }
+            Self::Bcrypt { cost } => bcrypt::non_truncating_hash_with_salt(password, cost, salt)
+                .map(|hash| hash.to_string())
+                .map_err(|e| e.to_string()),
+            Self::Scrypt { ln, r, p } => {
+                let ln = u8::try_from(ln).map_err(|e| e.to_string())?;
+                let params = scrypt::Params::new(ln, r, p).map_err(|e| e.to_string())?;
+                Scrypt::new_with_params(params)
+                    .hash_password_with_salt(password, &salt)
+                    .map(|hash| hash.to_string())
+                    .map_err(|e| e.to_string())
+            }
+        }
+    }
+
+    /// Check the password against a stored hash that uses this policy.
+    fn verify(self, phc: &str, password: &[u8]) -> Result<bool, String> {
+        match self {
+            Self::Argon2id { .. } => verify_phc(&Argon2::default(), phc, password),
+            Self::Bcrypt { .. } => bcrypt::verify(password, phc).map_err(|e| e.to_string()),
+            Self::Scrypt { .. } => verify_phc(&Scrypt::new(), phc, password),
+        }
+    }
+}
+
+fn check_range(name: &str, val: u32, range: RangeInclusive<u32>) -> Result<(), String> {
+    if range.contains(&val) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{name} must be between {} and {}, got {val}",
+            range.start(),
+            range.end()
+        ))
+    }
+}
+
+fn verify_phc(
+    verifier: &impl PasswordVerifier<PasswordHash>,
+    phc: &str,
+    password: &[u8],
+) -> Result<bool, String> {
+    let hash = PasswordHash::new(phc).map_err(|e| e.to_string())?;
+    match verifier.verify_password(password, &hash) {
+        Ok(()) => Ok(true),
+        Err(password_hash::Error::PasswordInvalid) => Ok(false),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Register the `password_hash`, `password_verify`, and `password_needs_rehash` SQL functions
+/// with the given `SQLite` connection.
+///
+/// * `password_hash(algo, password [, params])` hashes the password with `argon2id`, `bcrypt`, or `scrypt`
+///   using a random salt, and returns a [PHC string](https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md),
+///   or the `$2b$` modular crypt string for bcrypt. The optional `params` is a comma-separated list
+///   like `m=19456,t=2,p=1` for Argon2id, `cost=12` for bcrypt, or `ln=17,r=8,p=1` for scrypt.
+///   Omitted parameters use the [OWASP](https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html) recommended values.
+/// * `password_verify(phc, password)` returns `1` if the password matches the stored hash, and `0` otherwise.
+/// * `password_needs_rehash(phc, [algo,] params)` returns `1` if the stored hash does not use the given
+///   parameters, or the given algorithm if it is set. Without `algo`, the algorithm of the stored hash is kept,
+///   and without `params`, the recommended values are used.
+///
+/// If the password or the stored hash is `NULL`, the result is `NULL`. Costs are limited to prevent
+/// denial of service through a single call: Argon2id to 1 GiB of memory, 16 iterations and 16 lanes,
+/// bcrypt to cost 16, and scrypt to `ln=20`, `r=32`, `p=16`, and 1 GiB of memory.
+/// Stored hashes exceeding these limits are rejected with an error without being computed.
+///
+/// Unlike the digest functions, these functions are not registered as `SQLITE_DETERMINISTIC`,
+/// because every `password_hash` call returns a different string. They are always registered as
+/// `SQLITE_DIRECTONLY`, even by [`register_innocuous_hash_functions`](crate::register_innocuous_hash_functions),
+/// so they cannot be used in triggers, views, or other schema objects.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_password_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_password_functions(&db)?;
+/// let phc: String = db.query_row("SELECT password_hash('argon2id', 'hunter2', 'm=64,t=1,p=1')", [], |r| r.get(0))?;
+/// assert!(phc.starts_with("$argon2id$v=19$m=64,t=1,p=1$"));
+/// let valid: bool = db.query_row("SELECT password_verify(?, 'hunter2')", [&phc], |r| r.get(0))?;
+/// assert!(valid);
+/// let rehash: bool = db.query_row("SELECT password_needs_rehash(?, 'm=19456,t=2,p=1')", [&phc], |r| r.get(0))?;
+/// assert!(rehash);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_password_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+/// The flags of the registration mode are ignored, the functions always use [`PASSWORD_FLAGS`].
+pub(crate) fn register(conn: &Connection, _flags: FunctionFlags) -> Result<()> {
+    let flags = PASSWORD_FLAGS;
+
+    create_scalar_function(conn, "password_hash", flags, |ctx| {
+        if !has_args(ctx, 2, 3)? {
+            return Ok(None);
+        }
+        let params = Params::new("password_hash", ctx, ctx.len());
+        let algo = params.text(0, "algo")?;
+        let password = params.max_bytes(1, "password", MAX_PASSWORD_LEN)?;
+        let policy = Policy::new(algo)
+            .map_err(|e| params.error(0, "algo", e))?
+            .with_params(
+                params
+                    .is_set(2)
+                    .then(|| params.text(2, "params"))
+                    .transpose()?,
+            )
+            .map_err(|e| params.error(2, "params", e))?;
+        policy
+            .hash(password)
+            .map(Some)
+            .map_err(|e| UserFunctionError(format!("password_hash: {e}").into()))
+    })?;
+
+    create_scalar_function(conn, "password_verify", flags, |ctx| {
+        if !has_args(ctx, 2, 2)? {
+            return Ok(None);
+        }
+        let params = Params::new("password_verify", ctx, 2);
+        let phc = params.text(0, "phc")?;
+        let password = params.max_bytes(1, "password", MAX_PASSWORD_LEN)?;
+        Policy::from_hash(phc)
+            .and_then(|policy| policy.verify(phc, password))
+            .map(Some)
+            .map_err(|e| params.error(0, "phc", e))
+    })?;
+
+    create_scalar_function(conn, "password_needs_rehash", flags, |ctx| {
+        if !has_args(ctx, 1, 3)? {
+            return Ok(None);
+        }
+        let params = Params::new("password_needs_rehash", ctx, ctx.len());
+        let phc = params.text(0, "phc")?;
+        let current = Policy::from_hash(phc).map_err(|e| params.error(0, "phc", e))?;
+        let (algo, idx) = if ctx.len() == 3 {
+            (params.text(1, "algo")?, 2)
+        } else {
+            (current.name(), 1)
+        };
+        let wanted = Policy::new(algo)
+            .map_err(|e| params.error(1, "algo", e))?
+            .with_params(
+                params
+                    .is_set(idx)
+                    .then(|| params.text(idx, "params"))
+                    .transpose()?,
+            )
+            .map_err(|e| params.error(idx, "params", e))?;
+        Ok(Some(current != wanted))
+    })?;
+
+    Ok(())
+}
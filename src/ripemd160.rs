@@ -0,0 +1,36 @@
+use ripemd::Ripemd160;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, log_weak_algorithm, DIRECT_ONLY_FLAGS};
+
+/// Register the `ripemd160` SQL function with the given `SQLite` connection.
+/// The function takes a single argument and returns the [RIPEMD-160 hash](https://en.wikipedia.org/wiki/RIPEMD) (blob) of that argument.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// RIPEMD-160 is only provided for legacy data, e.g. Bitcoin addresses.
+/// Registering this function logs a notice with the `SQLite` logger.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_ripemd160_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_ripemd160_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT ripemd160('abc')", [], |r| r.get(0))?;
+/// let expected = b"\x8e\xb2\x08\xf7\xe0\x5d\x98\x7a\x9b\x04\x4a\x8e\x98\xc6\xb0\x87\xf1\x5a\x0b\xfc";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_ripemd160_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    log_weak_algorithm("ripemd160");
+    create_hash_fn::<Ripemd160>(conn, "ripemd160", flags)
+}
@@ -8,9 +8,20 @@ use rusqlite::ToSql;
 
 #[cfg(feature = "aggregate")]
 use crate::aggregate::create_agg_function;
+use crate::builder::Variants;
+#[cfg(feature = "hmac")]
+use crate::param::Hmac;
+use crate::param::{create_param_variants, Truncated};
 use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::types::{Type, ValueRef};
 use crate::rusqlite::Error::{InvalidFunctionParameterType, InvalidParameterCount};
+#[cfg(any(
+    feature = "md4",
+    feature = "ripemd160",
+    feature = "tiger",
+    feature = "whirlpool"
+))]
+use crate::rusqlite::{ffi::SQLITE_NOTICE, trace::log};
 use crate::rusqlite::{Connection, Result};
 use crate::state::HashState;
 
@@ -19,11 +30,69 @@ macro_rules! trace {
     ($($arg:tt)*) => {};
 }
 
+/// A [`Digest`] with a name, used as the base name of the registered SQL functions.
+/// Implement this trait to register a custom hashing algorithm with [`register_digest`].
 pub trait NamedDigest: Digest {
+    /// The base name of the SQL functions, e.g. `sha256`.
     fn name() -> &'static str;
+
+    /// The internal block size in bytes, used to compute HMAC, e.g. 64 for `sha256`.
+    /// Digests returning `None`, like the non-cryptographic checksums, do not get the `hmac_*` functions.
+    #[must_use]
+    fn block_size() -> Option<usize> {
+        None
+    }
+}
+
+/// Generic code to run with a digest chosen at runtime by its name, see `with_hmac_digest`.
+#[cfg(feature = "kdf")]
+#[allow(dead_code, reason = "not used by every feature combination")]
+pub(crate) trait DigestFn {
+    type Output;
+
+    fn call<T: NamedDigest + Clone>(self) -> Self::Output;
 }
 
 macro_rules! digest_names {
+    (hmac: $($typ:ty => $name:literal $(@ $feature:literal)?),* $(,)?) => {
+        $(
+            digest_names!(@hmac $typ => $name $(@ $feature)?);
+        )*
+
+        /// Run `func` with the enabled cryptographic digest called `name`, or return `None` if there is none.
+        #[cfg(feature = "kdf")]
+        #[allow(unused_variables, reason = "not used by every feature combination")]
+        pub(crate) fn with_hmac_digest<F: DigestFn>(name: &str, func: F) -> Option<F::Output> {
+            $(
+                digest_names!(@call name, func, $typ => $name $(@ $feature)?);
+            )*
+            None
+        }
+    };
+    (@hmac $typ:ty => $name:literal) => {
+        digest_names!(@hmac $typ => $name @ $name);
+    };
+    (@hmac $typ:ty => $name:literal @ $feature:literal) => {
+        #[cfg(feature = $feature)]
+        impl NamedDigest for $typ {
+            fn name() -> &'static str {
+                $name
+            }
+
+            fn block_size() -> Option<usize> {
+                Some(<$typ as digest::common::BlockSizeUser>::block_size())
+            }
+        }
+    };
+    (@call $name_var:ident, $func:ident, $typ:ty => $name:literal) => {
+        digest_names!(@call $name_var, $func, $typ => $name @ $name);
+    };
+    (@call $name_var:ident, $func:ident, $typ:ty => $name:literal @ $feature:literal) => {
+        #[cfg(feature = $feature)]
+        if $name_var.eq_ignore_ascii_case($name) {
+            return Some($func.call::<$typ>());
+        }
+    };
     ($($typ:ty => $name:literal),* $(,)?) => {
         digest_names!(
             $(
@@ -43,42 +112,182 @@ macro_rules! digest_names {
     };
 }
 
+// Cryptographic digests also get the HMAC functions, and can be used with the key derivation functions.
+// Explicitly specify the feature flags when the fn name is different.
 digest_names! {
+    hmac:
     md5::Md5 => "md5",
     sha1::Sha1 => "sha1",
     sha2::Sha224 => "sha224",
     sha2::Sha256 => "sha256",
     sha2::Sha384 => "sha384",
     sha2::Sha512 => "sha512",
+    sha2::Sha512_224 => "sha512_224",
+    sha2::Sha512_256 => "sha512_256",
+    sha3::Sha3_224 => "sha3_224" @ "sha3",
+    sha3::Sha3_256 => "sha3_256" @ "sha3",
+    sha3::Sha3_384 => "sha3_384" @ "sha3",
+    sha3::Sha3_512 => "sha3_512" @ "sha3",
+    sha3::Keccak256 => "keccak256" @ "keccak",
+    ascon_hash256::AsconHash256 => "ascon_hash256" @ "ascon",
+    sm3::Sm3 => "sm3",
+    streebog::Streebog256 => "streebog256",
+    streebog::Streebog512 => "streebog512",
+    blake2::Blake2b512 => "blake2b512" @ "blake2",
+    blake2::Blake2s256 => "blake2s256" @ "blake2",
     blake3::Hasher => "blake3",
+    md4::Md4 => "md4",
+    ripemd::Ripemd160 => "ripemd160",
+    tiger::Tiger => "tiger",
+    whirlpool::Whirlpool => "whirlpool",
 }
 
-// Explicitly specify the feature flags when the fn name is different
 digest_names! {
+    crate::Crc32 => "crc32" @ "crc",
+    crate::Crc32c => "crc32c" @ "crc",
+    crate::Crc64Ecma => "crc64_ecma" @ "crc",
+    crate::Crc64Xz => "crc64_xz" @ "crc",
+    crate::Adler32 => "adler32" @ "crc",
     noncrypto_digests::Fnv => "fnv1a" @ "fnv",
+    crate::Fnv1a_32 => "fnv1a_32" @ "fnv",
+    crate::Fnv1a_128 => "fnv1a_128" @ "fnv",
+    crate::Fnv1_32 => "fnv1_32" @ "fnv",
+    crate::Fnv1_64 => "fnv1_64" @ "fnv",
+    crate::Fnv1_128 => "fnv1_128" @ "fnv",
     noncrypto_digests::Xxh32 => "xxh32" @ "xxhash",
     noncrypto_digests::Xxh64 => "xxh64" @ "xxhash",
     noncrypto_digests::Xxh3_64 => "xxh3_64" @ "xxhash",
     noncrypto_digests::Xxh3_128 => "xxh3_128" @ "xxhash",
 }
 
+/// Flags used by the `register_*_functions` functions. Functions can only be used in top-level SQL,
+/// and not in triggers, views, `CHECK` constraints, generated columns, or indexes.
+pub(crate) const DIRECT_ONLY_FLAGS: FunctionFlags = FunctionFlags::SQLITE_UTF8
+    .union(FunctionFlags::SQLITE_DETERMINISTIC)
+    .union(FunctionFlags::SQLITE_DIRECTONLY);
+
+/// Flags used by [`register_innocuous_hash_functions`](crate::register_innocuous_hash_functions).
+/// Digest functions have no side effects, so they can be safely used anywhere in the schema.
+pub(crate) const INNOCUOUS_FLAGS: FunctionFlags = FunctionFlags::SQLITE_UTF8
+    .union(FunctionFlags::SQLITE_DETERMINISTIC)
+    .union(FunctionFlags::SQLITE_INNOCUOUS);
+
+/// Flags used by the password functions in every registration mode. They are not deterministic,
+/// and are too costly to be called implicitly from the schema, so they are only allowed in top-level SQL.
+#[cfg(any(feature = "crypt", feature = "password"))]
+pub(crate) const PASSWORD_FLAGS: FunctionFlags =
+    FunctionFlags::SQLITE_UTF8.union(FunctionFlags::SQLITE_DIRECTONLY);
+
+/// Report a legacy algorithm through the `SQLite` log when its functions are registered,
+/// so that applications using `sqlite3_config(SQLITE_CONFIG_LOG)` can spot remaining uses.
+#[cfg(any(
+    feature = "md4",
+    feature = "ripemd160",
+    feature = "tiger",
+    feature = "whirlpool"
+))]
+pub(crate) fn log_weak_algorithm(name: &str) {
+    log(
+        SQLITE_NOTICE,
+        &format!("sqlite-hashes: {name} is a weak hashing algorithm, use it only for legacy data"),
+    );
+}
+
+/// Register a user-supplied digest `T` with the given `SQLite` connection, using [`NamedDigest::name`]
+/// as the base function name. Just like the built-in algorithms, this registers
+/// `name(...)`, `name_hex(...)`, `name_concat(...)`, and `name_concat_hex(...)` functions,
+/// depending on the enabled `hex` and `aggregate` features. Argument types and `NULL` handling are the same as well.
+/// The same variants of `name_trunc(len, ...)` return the first `len` bytes of the hash.
+/// With the `hmac` feature, digests returning [`NamedDigest::block_size`] also get `hmac_name(key, ...)` and its variants.
+/// Use [`HashFunctions::builder`](crate::HashFunctions::builder) to customize names, variants, or flags.
+///
+/// The digest must satisfy these bounds:
+/// * [`NamedDigest`] - provides the hashing itself via the [`Digest`] trait, and the function name.
+///   Any `RustCrypto`-compatible hasher implementing `Default + Update + FixedOutput + HashMarker` is a [`Digest`].
+/// * [`Clone`] - required by the `SQLite` aggregate function machinery.
+/// * [`UnwindSafe`] and [`RefUnwindSafe`] - hashing state is kept across a panic boundary inside `SQLite` callbacks.
+/// * `'static` - the functions are owned by the connection, and may outlive the caller.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::{register_digest, NamedDigest};
+/// use digest::{consts::U1, FixedOutput, HashMarker, Output, OutputSizeUser, Update};
+///
+/// /// A toy checksum that XORs all bytes together
+/// #[derive(Clone, Default)]
+/// struct Xor8(u8);
+///
+/// impl HashMarker for Xor8 {}
+/// impl OutputSizeUser for Xor8 {
+///     type OutputSize = U1;
+/// }
+/// impl Update for Xor8 {
+///     fn update(&mut self, data: &[u8]) {
+///         self.0 = data.iter().fold(self.0, |acc, v| acc ^ v);
+///     }
+/// }
+/// impl FixedOutput for Xor8 {
+///     fn finalize_into(self, out: &mut Output<Self>) {
+///         out[0] = self.0;
+///     }
+/// }
+/// impl NamedDigest for Xor8 {
+///     fn name() -> &'static str {
+///         "xor8"
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_digest::<Xor8>(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT xor8(x'0102', x'04')", [], |r| r.get(0))?;
+/// assert_eq!(hash, [7]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_digest<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static>(
+    conn: &Connection,
+) -> Result<()> {
+    create_hash_fn::<T>(conn, T::name(), DIRECT_ONLY_FLAGS)
+}
+
 pub(crate) fn create_hash_fn<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static>(
     conn: &Connection,
     fn_name: &str,
+    flags: FunctionFlags,
 ) -> Result<()> {
-    create_scalar_function(conn, fn_name, |c| {
-        hash_fn::<T>(
-            c,
-            #[cfg(feature = "trace")]
-            "",
-        )
-        .map(HashState::finalize)
-    })?;
+    create_hash_variants::<T>(conn, "", fn_name, Variants::ALL, flags)
+}
+
+/// Register the requested `variants` of the `T` digest, using `prefix` and `name` as the base name,
+/// e.g. `{prefix}{name}_hex` and `{prefix}hmac_{name}_hex`.
+pub(crate) fn create_hash_variants<
+    T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static,
+>(
+    conn: &Connection,
+    prefix: &str,
+    name: &str,
+    variants: Variants,
+    flags: FunctionFlags,
+) -> Result<()> {
+    let fn_name = format!("{prefix}{name}");
+    if variants.contains(Variants::SCALAR) {
+        create_scalar_function(conn, &fn_name, flags, |c| {
+            hash_fn::<T>(
+                c,
+                #[cfg(feature = "trace")]
+                "",
+            )
+            .map(HashState::finalize)
+        })?;
+    }
 
     #[cfg(feature = "hex")]
-    {
+    if variants.contains(Variants::HEX) {
         let fn_name = format!("{fn_name}_hex");
-        create_scalar_function(conn, &fn_name, |c| {
+        create_scalar_function(conn, &fn_name, flags, |c| {
             hash_fn::<T>(
                 c,
                 #[cfg(feature = "trace")]
@@ -89,11 +298,12 @@ pub(crate) fn create_hash_fn<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe
     }
 
     #[cfg(feature = "aggregate")]
-    {
+    if variants.contains(Variants::CONCAT) {
         let fn_name = format!("{fn_name}_concat");
         create_agg_function(
             conn,
             &fn_name,
+            flags,
             crate::aggregate::AggType::<T, Vec<u8>>::new(
                 #[cfg(feature = "trace")]
                 &fn_name,
@@ -103,11 +313,12 @@ pub(crate) fn create_hash_fn<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe
     }
 
     #[cfg(all(feature = "aggregate", feature = "hex"))]
-    {
+    if variants.contains(Variants::CONCAT_HEX) {
         let fn_name = format!("{fn_name}_concat_hex");
         create_agg_function(
             conn,
             &fn_name,
+            flags,
             crate::aggregate::AggType::<T, String>::new(
                 #[cfg(feature = "trace")]
                 &fn_name,
@@ -116,24 +327,71 @@ pub(crate) fn create_hash_fn<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe
         )?;
     }
 
+    if variants.contains(Variants::TRUNC) {
+        let fn_name = format!("{fn_name}_trunc");
+        create_param_variants::<Truncated<T>>(conn, &fn_name, variants, flags)?;
+    }
+
+    #[cfg(feature = "hmac")]
+    if variants.contains(Variants::HMAC) && T::block_size().is_some() {
+        let fn_name = format!("{prefix}hmac_{name}");
+        create_param_variants::<Hmac<T>>(conn, &fn_name, variants, flags)?;
+    }
+
     Ok(())
 }
 
-pub fn create_scalar_function<F, T>(conn: &Connection, fn_name: &str, function: F) -> Result<()>
+/// Register `fn_name_int` and `fn_name_int_concat` functions returning the `T` digest as an integer.
+/// The digest must not be longer than 8 bytes.
+#[cfg(feature = "crc")]
+pub(crate) fn create_int_hash_fn<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static>(
+    conn: &Connection,
+    fn_name: &str,
+    flags: FunctionFlags,
+) -> Result<()> {
+    const { assert!(<T::OutputSize as digest::typenum::Unsigned>::USIZE <= 8) };
+
+    let int_name = format!("{fn_name}_int");
+    create_scalar_function(conn, &int_name, flags, |c| {
+        hash_fn::<T>(
+            c,
+            #[cfg(feature = "trace")]
+            "_int",
+        )
+        .map(HashState::finalize_int)
+    })?;
+
+    #[cfg(feature = "aggregate")]
+    {
+        let fn_name = format!("{fn_name}_int_concat");
+        create_agg_function(
+            conn,
+            &fn_name,
+            flags,
+            crate::aggregate::AggType::<T, i64>::new(
+                #[cfg(feature = "trace")]
+                &fn_name,
+                HashState::finalize_int,
+            ),
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn create_scalar_function<F, T>(
+    conn: &Connection,
+    fn_name: &str,
+    flags: FunctionFlags,
+    function: F,
+) -> Result<()>
 where
     // TODO: Newer versions do not require UnwindSafe
     F: Fn(&Context<'_>) -> Result<T> + Send + UnwindSafe + 'static,
     T: ToSql,
 {
     trace!("Registering function {fn_name}");
-    conn.create_scalar_function(
-        fn_name,
-        -1,
-        FunctionFlags::SQLITE_UTF8
-            | FunctionFlags::SQLITE_DETERMINISTIC
-            | FunctionFlags::SQLITE_DIRECTONLY,
-        function,
-    )
+    conn.create_scalar_function(fn_name, -1, flags, function)
 }
 
 fn hash_fn<T: NamedDigest + Clone + UnwindSafe + RefUnwindSafe + 'static>(
@@ -1,6 +1,8 @@
 use sha1::Sha1;
 
+use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
 
 /// Register the `sha1` SQL function with the given `SQLite` connection.
 /// The function takes a single argument and returns the [SHA1 hash](https://en.wikipedia.org/wiki/SHA-1) (blob) of that argument.
@@ -22,5 +24,9 @@ use crate::rusqlite::{Connection, Result};
 /// # }
 /// ```
 pub fn register_sha1_functions(conn: &Connection) -> Result<()> {
-    crate::scalar::create_hash_fn::<Sha1>(conn, "sha1")
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Sha1>(conn, "sha1", flags)
 }
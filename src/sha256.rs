@@ -1,12 +1,16 @@
 use sha2::Sha256;
 
+use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
 
-/// Register the `sha256` SQL function with the given `SQLite` connection.
+/// Register the `sha256` and `sha256_trunc` SQL functions with the given `SQLite` connection.
 /// The function takes a single argument and returns the [SHA256 hash](https://en.wikipedia.org/wiki/SHA-2) (blob) of that argument.
 /// The argument can be either a string or a blob.
 /// If the argument is `NULL`, the result is `NULL`.
 ///
+/// `sha256_trunc(len, ...)` returns the first `len` bytes of the same hash, with `len` between 1 and 32.
+///
 /// # Example
 ///
 /// ```
@@ -18,9 +22,15 @@ use crate::rusqlite::{Connection, Result};
 /// let hash: Vec<u8> = db.query_row("SELECT sha256('hello')", [], |r| r.get(0))?;
 /// let expected = b"\x2c\xf2\x4d\xba\x5f\xb0\xa3\x0e\x26\xe8\x3b\x2a\xc5\xb9\xe2\x9e\x1b\x16\x1e\x5c\x1f\xa7\x42\x5e\x73\x04\x33\x62\x93\x8b\x98\x24";
 /// assert_eq!(hash, expected);
+/// let hash: Vec<u8> = db.query_row("SELECT sha256_trunc(8, 'hello')", [], |r| r.get(0))?;
+/// assert_eq!(hash, &expected[..8]);
 /// # Ok(())
 /// # }
 /// ```
 pub fn register_sha256_functions(conn: &Connection) -> Result<()> {
-    crate::scalar::create_hash_fn::<Sha256>(conn, "sha256")
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Sha256>(conn, "sha256", flags)
 }
@@ -0,0 +1,35 @@
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Register `sha3_224`, `sha3_256`, `sha3_384`, and `sha3_512` SQL functions with the given `SQLite` connection.
+/// The functions compute the [SHA-3 hash](https://en.wikipedia.org/wiki/SHA-3) (blob) of the argument(s).
+/// The arguments can be either strings or blobs.
+/// If all arguments are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_sha3_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_sha3_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT sha3_256('hello')", [], |r| r.get(0))?;
+/// let expected = b"\x33\x38\xbe\x69\x4f\x50\xc5\xf3\x38\x81\x49\x86\xcd\xf0\x68\x64\x53\xa8\x88\xb8\x4f\x42\x4d\x79\x2a\xf4\xb9\x20\x23\x98\xf3\x92";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_sha3_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Sha3_224>(conn, "sha3_224", flags)?;
+    create_hash_fn::<Sha3_256>(conn, "sha3_256", flags)?;
+    create_hash_fn::<Sha3_384>(conn, "sha3_384", flags)?;
+    create_hash_fn::<Sha3_512>(conn, "sha3_512", flags)
+}
@@ -1,12 +1,16 @@
 use sha2::Sha384;
 
+use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
 
-/// Register the `sha384` SQL function with the given `SQLite` connection.
+/// Register the `sha384` and `sha384_trunc` SQL functions with the given `SQLite` connection.
 /// The function takes a single argument and returns the [SHA384 hash](https://en.wikipedia.org/wiki/SHA-2) (blob) of that argument.
 /// The argument can be either a string or a blob.
 /// If the argument is `NULL`, the result is `NULL`.
 ///
+/// `sha384_trunc(len, ...)` returns the first `len` bytes of the same hash, with `len` between 1 and 48.
+///
 /// # Example
 ///
 /// ```
@@ -22,5 +26,9 @@ use crate::rusqlite::{Connection, Result};
 /// # }
 /// ```
 pub fn register_sha384_functions(conn: &Connection) -> Result<()> {
-    crate::scalar::create_hash_fn::<Sha384>(conn, "sha384")
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Sha384>(conn, "sha384", flags)
 }
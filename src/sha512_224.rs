@@ -0,0 +1,36 @@
+use sha2::Sha512_224;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Register the `sha512_224` and `sha512_224_trunc` SQL functions with the given `SQLite` connection.
+/// The function takes a single argument and returns the [SHA-512/224 hash](https://en.wikipedia.org/wiki/SHA-2) (blob) of that argument.
+/// SHA-512/224 is SHA512 with a distinct initial value, truncated to 224 bits, and is usually faster than
+/// SHA256 on 64-bit CPUs.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// `sha512_224_trunc(len, ...)` returns the first `len` bytes of the same hash, with `len` between 1 and 28.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_sha512_224_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_sha512_224_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT sha512_224('hello')", [], |r| r.get(0))?;
+/// let expected = b"\xfe\x85\x09\xed\x1f\xb7\xdc\xef\xc2\x7e\x6a\xc1\xa8\x0e\xdd\xbe\xc4\xcb\x3d\x2c\x6f\xe5\x65\x24\x43\x74\x06\x1c";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_sha512_224_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Sha512_224>(conn, "sha512_224", flags)
+}
@@ -0,0 +1,36 @@
+use sha2::Sha512_256;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Register the `sha512_256` and `sha512_256_trunc` SQL functions with the given `SQLite` connection.
+/// The function takes a single argument and returns the [SHA-512/256 hash](https://en.wikipedia.org/wiki/SHA-2) (blob) of that argument.
+/// SHA-512/256 is SHA512 with a distinct initial value, truncated to 256 bits, and is usually faster than
+/// SHA256 on 64-bit CPUs.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// `sha512_256_trunc(len, ...)` returns the first `len` bytes of the same hash, with `len` between 1 and 32.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_sha512_256_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_sha512_256_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT sha512_256('hello')", [], |r| r.get(0))?;
+/// let expected = b"\xe3\x0d\x87\xcf\xa2\xa7\x5d\xb5\x45\xea\xc4\xd6\x1b\xaf\x97\x03\x66\xa8\x35\x7c\x7f\x72\xfa\x95\xb5\x2d\x0a\xcc\xb6\x98\xf1\x3a";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_sha512_256_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Sha512_256>(conn, "sha512_256", flags)
+}
@@ -0,0 +1,35 @@
+use shake::{Shake128, Shake256};
+
+use crate::param::{create_param_hash_fn, XofLen};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::DIRECT_ONLY_FLAGS;
+
+/// Register `shake128` and `shake256` SQL functions with the given `SQLite` connection.
+/// The functions compute the [SHAKE extendable-output function](https://en.wikipedia.org/wiki/SHA-3) (blob)
+/// of the argument(s). The first argument is the output length in bytes, between 1 and 1048576.
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_shake_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_shake_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT shake128(8, 'hello')", [], |r| r.get(0))?;
+/// let expected = b"\x8e\xb4\xb6\xa9\x32\xf2\x80\x33";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_shake_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_param_hash_fn::<XofLen<Shake128>>(conn, "shake128", flags)?;
+    create_param_hash_fn::<XofLen<Shake256>>(conn, "shake256", flags)
+}
@@ -0,0 +1,138 @@
+use ed25519_dalek::Signer as _;
+use p256::ecdsa::signature::hazmat::PrehashVerifier as _;
+use p256::ecdsa::signature::Verifier as _;
+
+use crate::param::{has_args, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_scalar_function, DIRECT_ONLY_FLAGS};
+
+/// Verify an Ed25519 signature, rejecting the malformed signatures, and the weak public keys.
+fn ed25519_verify(pubkey: &[u8; 32], message: &[u8], signature: &[u8]) -> bool {
+    let Ok(key) = ed25519_dalek::VerifyingKey::from_bytes(pubkey) else {
+        return false;
+    };
+    let Ok(signature) = ed25519_dalek::Signature::from_slice(signature) else {
+        return false;
+    };
+    key.verify_strict(message, &signature).is_ok()
+}
+
+/// Parse a fixed-size `r || s` ECDSA signature of 64 bytes, or an ASN.1 DER-encoded one.
+fn p256_signature(signature: &[u8]) -> Option<p256::ecdsa::Signature> {
+    if signature.len() == 64 {
+        p256::ecdsa::Signature::from_slice(signature).ok()
+    } else {
+        p256::ecdsa::Signature::from_der(signature).ok()
+    }
+}
+
+/// Get the SEC1-encoded P-256 public key, either compressed (33 bytes) or uncompressed (65 bytes).
+/// Returns `None` if the key has the right length, but is not a point on the curve.
+fn p256_pubkey(params: &Params, idx: usize) -> Result<Option<p256::ecdsa::VerifyingKey>> {
+    let pubkey = params.bytes(idx, "pubkey")?;
+    if pubkey.len() != 33 && pubkey.len() != 65 {
+        return Err(params.error(
+            idx,
+            "pubkey",
+            format_args!(
+                "must be a 33 or 65 bytes SEC1-encoded public key, got {} bytes",
+                pubkey.len()
+            ),
+        ));
+    }
+    Ok(p256::ecdsa::VerifyingKey::from_sec1_bytes(pubkey).ok())
+}
+
+/// Register the `ed25519_verify`, `ed25519_sign`, `ed25519_public_key`, `ecdsa_p256_verify`,
+/// and `ecdsa_p256_verify_digest` SQL functions with the given `SQLite` connection.
+///
+/// * `ed25519_verify(pubkey, message, signature)` returns `1` if the 64-byte signature of the message
+///   is valid for the 32-byte public key, as defined by [RFC 8032](https://www.rfc-editor.org/rfc/rfc8032.html).
+/// * `ed25519_sign(secret_key, message)` signs the message with the 32-byte secret key, e.g. to create test fixtures.
+/// * `ed25519_public_key(secret_key)` returns the 32-byte public key of the secret key.
+/// * `ecdsa_p256_verify(pubkey, message, signature)` returns `1` if the ECDSA signature of the SHA-256 hash
+///   of the message is valid for the SEC1-encoded P-256 public key (33 or 65 bytes).
+/// * `ecdsa_p256_verify_digest(pubkey, digest, signature)` is the same, but with the 32-byte SHA-256 digest,
+///   e.g. computed by `sha256(...)`.
+///
+/// The ECDSA signature is either 64 bytes of `r || s`, or ASN.1 DER-encoded.
+/// Malformed signatures and public keys that are not on the curve are not valid, and return `0`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_signature_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_signature_functions(&db)?;
+/// let sql = "SELECT ed25519_verify(ed25519_public_key(?1), 'message', ed25519_sign(?1, 'message'))";
+/// let valid: bool = db.query_row(sql, [[7_u8; 32]], |r| r.get(0))?;
+/// assert!(valid);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_signature_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_scalar_function(conn, "ed25519_verify", flags, |ctx| {
+        if !has_args(ctx, 3, 3)? {
+            return Ok(None);
+        }
+        let params = Params::new("ed25519_verify", ctx, 3);
+        let pubkey = params.key::<32>(0, "pubkey")?;
+        let message = params.bytes(1, "message")?;
+        let signature = params.bytes(2, "signature")?;
+        Ok(Some(ed25519_verify(pubkey, message, signature)))
+    })?;
+
+    create_scalar_function(conn, "ed25519_sign", flags, |ctx| {
+        if !has_args(ctx, 2, 2)? {
+            return Ok(None);
+        }
+        let params = Params::new("ed25519_sign", ctx, 2);
+        let key = ed25519_dalek::SigningKey::from_bytes(params.key::<32>(0, "secret_key")?);
+        let message = params.bytes(1, "message")?;
+        Ok(Some(key.sign(message).to_bytes().to_vec()))
+    })?;
+
+    create_scalar_function(conn, "ed25519_public_key", flags, |ctx| {
+        if !has_args(ctx, 1, 1)? {
+            return Ok(None);
+        }
+        let params = Params::new("ed25519_public_key", ctx, 1);
+        let key = ed25519_dalek::SigningKey::from_bytes(params.key::<32>(0, "secret_key")?);
+        Ok(Some(key.verifying_key().to_bytes().to_vec()))
+    })?;
+
+    create_scalar_function(conn, "ecdsa_p256_verify", flags, |ctx| {
+        if !has_args(ctx, 3, 3)? {
+            return Ok(None);
+        }
+        let params = Params::new("ecdsa_p256_verify", ctx, 3);
+        let pubkey = p256_pubkey(&params, 0)?;
+        let message = params.bytes(1, "message")?;
+        let signature = p256_signature(params.bytes(2, "signature")?);
+        Ok(Some(pubkey.zip(signature).is_some_and(
+            |(key, signature)| key.verify(message, &signature).is_ok(),
+        )))
+    })?;
+
+    create_scalar_function(conn, "ecdsa_p256_verify_digest", flags, |ctx| {
+        if !has_args(ctx, 3, 3)? {
+            return Ok(None);
+        }
+        let params = Params::new("ecdsa_p256_verify_digest", ctx, 3);
+        let pubkey = p256_pubkey(&params, 0)?;
+        let digest = params.key::<32>(1, "digest")?;
+        let signature = p256_signature(params.bytes(2, "signature")?);
+        Ok(Some(pubkey.zip(signature).is_some_and(
+            |(key, signature)| key.verify_prehash(digest, &signature).is_ok(),
+        )))
+    })?;
+
+    Ok(())
+}
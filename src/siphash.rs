@@ -0,0 +1,133 @@
+use std::hash::Hasher as _;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+use siphasher::sip::{SipHasher13, SipHasher24};
+use siphasher::sip128::{Hasher128 as _, SipHasher24 as SipHasher128};
+
+use crate::param::{create_param_hash_fn, create_param_int_hash_fn, ParamDigest, Params};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::DIRECT_ONLY_FLAGS;
+
+/// A `siphasher` hasher created from a 16-byte key.
+pub(crate) trait SipHasher: Clone + UnwindSafe + RefUnwindSafe + 'static {
+    fn new_with_key(key: &[u8; 16]) -> Self;
+
+    fn write(&mut self, data: &[u8]);
+
+    fn output(&self) -> Vec<u8>;
+}
+
+impl SipHasher for SipHasher24 {
+    fn new_with_key(key: &[u8; 16]) -> Self {
+        Self::new_with_key(key)
+    }
+
+    fn write(&mut self, data: &[u8]) {
+        std::hash::Hasher::write(self, data);
+    }
+
+    fn output(&self) -> Vec<u8> {
+        self.finish().to_le_bytes().to_vec()
+    }
+}
+
+impl SipHasher for SipHasher13 {
+    fn new_with_key(key: &[u8; 16]) -> Self {
+        Self::new_with_key(key)
+    }
+
+    fn write(&mut self, data: &[u8]) {
+        std::hash::Hasher::write(self, data);
+    }
+
+    fn output(&self) -> Vec<u8> {
+        self.finish().to_le_bytes().to_vec()
+    }
+}
+
+impl SipHasher for SipHasher128 {
+    fn new_with_key(key: &[u8; 16]) -> Self {
+        Self::new_with_key(key)
+    }
+
+    fn write(&mut self, data: &[u8]) {
+        std::hash::Hasher::write(self, data);
+    }
+
+    fn output(&self) -> Vec<u8> {
+        self.finish128().as_bytes().to_vec()
+    }
+}
+
+/// SipHash-2-4 or SipHash-1-3 hasher with the 16-byte key as the first parameter.
+#[derive(Clone)]
+pub(crate) struct SipHash<T>(T);
+
+impl<T: SipHasher> ParamDigest for SipHash<T> {
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        Ok(Self(T::new_with_key(params.key(0, "key")?)))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.write(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.0.output()
+    }
+
+    /// The `u64` value of the little-endian hash.
+    fn to_int(hash: &[u8]) -> i64 {
+        hash.iter()
+            .rev()
+            .fold(0, |acc, v| (acc << 8) | i64::from(*v))
+    }
+}
+
+/// Register the `siphash24`, `siphash13`, and `siphash128` SQL functions with the given `SQLite` connection.
+/// The functions compute the [SipHash](https://en.wikipedia.org/wiki/SipHash) keyed hash of the argument(s),
+/// with the key as the first argument, which must be exactly 16 bytes.
+///
+/// * `siphash24(key, ...)` is SipHash-2-4, as used by Redis and older Rust versions, returned as the 8-byte blob.
+/// * `siphash13(key, ...)` is the faster SipHash-1-3, as used by Rust's `HashMap`, in the same format.
+/// * `siphash128(key, ...)` is the 16-byte SipHash-2-4 variant.
+///
+/// All blobs have the same byte order as the reference implementation, i.e. the little-endian `u64` value.
+/// `siphash24_int(key, ...)` and `siphash13_int(key, ...)` return that `u64` value as an integer,
+/// e.g. for bucketing. Values above `9223372036854775807` are returned as negative integers (two's complement).
+///
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_siphash_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_siphash_functions(&db)?;
+/// let key = "x'000102030405060708090A0B0C0D0E0F'";
+/// let sql = format!("SELECT siphash24({key}, x'00010203')");
+/// let hash: Vec<u8> = db.query_row(&sql, [], |r| r.get(0))?;
+/// assert_eq!(hash, b"\xB7\x87\x71\x27\xE0\x94\x27\xCF");
+/// let sql = format!("SELECT siphash24_int({key}, x'00010203')");
+/// let hash: i64 = db.query_row(&sql, [], |r| r.get(0))?;
+/// assert_eq!(hash, i64::from_be_bytes(0xCF27_94E0_2771_87B7_u64.to_be_bytes()));
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_siphash_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_param_hash_fn::<SipHash<SipHasher24>>(conn, "siphash24", flags)?;
+    create_param_int_hash_fn::<SipHash<SipHasher24>>(conn, "siphash24", flags)?;
+    create_param_hash_fn::<SipHash<SipHasher13>>(conn, "siphash13", flags)?;
+    create_param_int_hash_fn::<SipHash<SipHasher13>>(conn, "siphash13", flags)?;
+    create_param_hash_fn::<SipHash<SipHasher128>>(conn, "siphash128", flags)
+}
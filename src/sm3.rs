@@ -0,0 +1,33 @@
+use sm3::Sm3;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Register the `sm3` SQL function with the given `SQLite` connection.
+/// The function takes a single argument and returns the [SM3 hash](https://en.wikipedia.org/wiki/SM3_(hash_function)) (blob) of that argument,
+/// as standardized in the Chinese GM/T 0004-2012.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_sm3_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_sm3_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT sm3('hello')", [], |r| r.get(0))?;
+/// let expected = b"\xbe\xcb\xbf\xaa\xe6\x54\x8b\x8b\xf0\xcf\xca\xd5\xa2\x71\x83\xcd\x1b\xe6\x09\x3b\x1c\xce\xcc\xc3\x03\xd9\xc6\x1d\x0a\x64\x52\x68";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_sm3_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Sm3>(conn, "sm3", flags)
+}
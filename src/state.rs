@@ -40,6 +40,13 @@ impl<T: Digest + Clone> HashState<T> {
         }
     }
 
+    /// Returns the digest of up to 8 bytes as a big-endian integer.
+    /// Values above `i64::MAX` are returned as their two's complement negative value.
+    #[inline]
+    pub fn finalize_int(self) -> Option<i64> {
+        self.finalize().map(|hash| to_int(&hash))
+    }
+
     #[inline]
     #[cfg(feature = "hex")]
     pub fn finalize_hex(self) -> Option<String> {
@@ -50,3 +57,9 @@ impl<T: Digest + Clone> HashState<T> {
         }
     }
 }
+
+/// Convert a big-endian hash of up to 8 bytes into an integer.
+pub(crate) fn to_int(hash: &[u8]) -> i64 {
+    let value = hash.iter().fold(0_u64, |acc, v| (acc << 8) | u64::from(*v));
+    i64::from_ne_bytes(value.to_ne_bytes())
+}
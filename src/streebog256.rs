@@ -0,0 +1,33 @@
+use streebog::Streebog256;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Register the `streebog256` SQL function with the given `SQLite` connection.
+/// The function takes a single argument and returns the 256-bit [Streebog hash](https://en.wikipedia.org/wiki/Streebog) (blob) of that argument,
+/// as standardized in the Russian GOST R 34.11-2012.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_streebog256_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_streebog256_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT streebog256('hello')", [], |r| r.get(0))?;
+/// let expected = b"\x3f\xb0\x70\x0a\x41\xce\x6e\x41\x41\x3b\xa7\x64\xf9\x8b\xf2\x13\x5b\xa6\xde\xd5\x16\xbe\xa2\xfa\xe8\x42\x9c\xc5\xbd\xd4\x6d\x6d";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_streebog256_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Streebog256>(conn, "streebog256", flags)
+}
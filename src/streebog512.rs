@@ -0,0 +1,33 @@
+use streebog::Streebog512;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Register the `streebog512` SQL function with the given `SQLite` connection.
+/// The function takes a single argument and returns the 512-bit [Streebog hash](https://en.wikipedia.org/wiki/Streebog) (blob) of that argument,
+/// as standardized in the Russian GOST R 34.11-2012.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_streebog512_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_streebog512_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT streebog512('')", [], |r| r.get(0))?;
+/// assert_eq!(hash.len(), 64);
+/// assert_eq!(&hash[..8], b"\x8e\x94\x5d\xa2\x09\xaa\x86\x9f");
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_streebog512_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Streebog512>(conn, "streebog512", flags)
+}
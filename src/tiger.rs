@@ -0,0 +1,37 @@
+use tiger::Tiger;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, log_weak_algorithm, DIRECT_ONLY_FLAGS};
+
+/// Register the `tiger` SQL function with the given `SQLite` connection.
+/// The function takes a single argument and returns the 192-bit [Tiger hash](https://en.wikipedia.org/wiki/Tiger_(hash_function)) (blob) of that argument,
+/// in the byte order of the reference implementation.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// Tiger is only provided for legacy data, e.g. old file sharing and integrity databases.
+/// Registering this function logs a notice with the `SQLite` logger.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_tiger_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_tiger_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT tiger('abc')", [], |r| r.get(0))?;
+/// let expected = b"\x2a\xab\x14\x84\xe8\xc1\x58\xf2\xbf\xb8\xc5\xff\x41\xb5\x7a\x52\x51\x29\x13\x1c\x95\x7b\x5f\x93";
+/// assert_eq!(hash, expected);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_tiger_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    log_weak_algorithm("tiger");
+    create_hash_fn::<Tiger>(conn, "tiger", flags)
+}
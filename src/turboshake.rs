@@ -0,0 +1,136 @@
+use keccak_p::{Keccak, State1600};
+
+use crate::param::{create_param_hash_fn, ParamDigest, Params, MAX_OUTPUT_LEN};
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::DIRECT_ONLY_FLAGS;
+
+/// Number of the Keccak-p\[1600\] rounds used by TurboSHAKE.
+const ROUNDS: usize = 12;
+
+/// [TurboSHAKE](https://www.rfc-editor.org/rfc/rfc9861.html) sponge with the output length and
+/// the domain separation byte as the first two parameters. `RATE` is 168 for TurboSHAKE128, and 136 for TurboSHAKE256.
+///
+/// The `turboshake` crate only supports the domain separation byte known at compile time,
+/// so the sponge is implemented here on top of the 12-round Keccak-p\[1600\] permutation.
+#[derive(Clone)]
+pub(crate) struct TurboShake<const RATE: usize> {
+    keccak: Keccak,
+    state: State1600,
+    pos: usize,
+    domain: u8,
+    len: usize,
+}
+
+impl<const RATE: usize> TurboShake<RATE> {
+    fn permute(&mut self) {
+        let state = &mut self.state;
+        self.keccak.with_p1600::<ROUNDS>(|p1600| p1600(state));
+        self.pos = 0;
+    }
+
+    fn xor_byte(&mut self, value: u8) {
+        self.state[self.pos / 8] ^= u64::from(value) << (8 * (self.pos % 8));
+        self.pos += 1;
+    }
+}
+
+impl<const RATE: usize> ParamDigest for TurboShake<RATE> {
+    const PARAMS: usize = 2;
+
+    fn new(params: &Params) -> Result<Self> {
+        let len = params.length(0, "len", 1..=MAX_OUTPUT_LEN)?;
+        let domain = params.int(1, "domain")?;
+        let domain = u8::try_from(domain)
+            .ok()
+            .filter(|v| (0x01..=0x7F).contains(v))
+            .ok_or_else(|| {
+                params.error(
+                    1,
+                    "domain",
+                    format_args!("must be between 1 and 127, got {domain}"),
+                )
+            })?;
+        Ok(Self {
+            keccak: Keccak::new(),
+            state: State1600::default(),
+            pos: 0,
+            domain,
+            len,
+        })
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.pos == 0 {
+                // Absorb whole blocks one 64-bit word at a time
+                while let Some((block, rest)) = data.split_first_chunk::<RATE>() {
+                    for (word, chunk) in self.state.iter_mut().zip(block.chunks_exact(8)) {
+                        *word ^= chunk
+                            .iter()
+                            .rev()
+                            .fold(0, |acc, v| (acc << 8) | u64::from(*v));
+                    }
+                    self.permute();
+                    data = rest;
+                }
+            }
+            let count = data.len().min(RATE - self.pos);
+            for &value in &data[..count] {
+                self.xor_byte(value);
+            }
+            if self.pos == RATE {
+                self.permute();
+            }
+            data = &data[count..];
+        }
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        self.xor_byte(self.domain);
+        self.state[RATE / 8 - 1] ^= 0x80 << 56;
+        let mut output = Vec::with_capacity(self.len);
+        while output.len() < self.len {
+            self.permute();
+            let count = (self.len - output.len()).min(RATE);
+            output.extend(
+                self.state
+                    .iter()
+                    .flat_map(|word| word.to_le_bytes())
+                    .take(count),
+            );
+        }
+        output
+    }
+}
+
+/// Register `turboshake128` and `turboshake256` SQL functions with the given `SQLite` connection.
+/// The functions compute the [TurboSHAKE](https://www.rfc-editor.org/rfc/rfc9861.html) extendable-output
+/// function (blob) of the argument(s), a faster 12-round variant of SHAKE.
+///
+/// The first argument is the output length in bytes, between 1 and 1048576, and the second one is the
+/// domain separation byte, between 1 and 127. Use 31 (`0x1F`) if the domain separation is not needed.
+/// The other arguments can be either strings or blobs.
+/// If all of them are `NULL`, the result is `NULL`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_turboshake_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_turboshake_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT turboshake128(8, 31, '')", [], |r| r.get(0))?;
+/// assert_eq!(hash, b"\x1e\x41\x5f\x1c\x59\x83\xaf\xf2");
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_turboshake_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_param_hash_fn::<TurboShake<168>>(conn, "turboshake128", flags)?;
+    create_param_hash_fn::<TurboShake<136>>(conn, "turboshake256", flags)
+}
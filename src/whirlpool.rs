@@ -0,0 +1,36 @@
+use whirlpool::Whirlpool;
+
+use crate::rusqlite::functions::FunctionFlags;
+use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, log_weak_algorithm, DIRECT_ONLY_FLAGS};
+
+/// Register the `whirlpool` SQL function with the given `SQLite` connection.
+/// The function takes a single argument and returns the 512-bit [Whirlpool hash](https://en.wikipedia.org/wiki/Whirlpool_(hash_function)) (blob) of that argument.
+/// The argument can be either a string or a blob.
+/// If the argument is `NULL`, the result is `NULL`.
+///
+/// Whirlpool is only provided for legacy data, e.g. old disk encryption and archive checksums.
+/// Registering this function logs a notice with the `SQLite` logger.
+///
+/// # Example
+///
+/// ```
+/// # use sqlite_hashes::rusqlite::{Connection, Result};
+/// # use sqlite_hashes::register_whirlpool_functions;
+/// # fn main() -> Result<()> {
+/// let db = Connection::open_in_memory()?;
+/// register_whirlpool_functions(&db)?;
+/// let hash: Vec<u8> = db.query_row("SELECT whirlpool('')", [], |r| r.get(0))?;
+/// assert_eq!(hash.len(), 64);
+/// assert_eq!(&hash[..8], b"\x19\xfa\x61\xd7\x55\x22\xa4\x66");
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_whirlpool_functions(conn: &Connection) -> Result<()> {
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    log_weak_algorithm("whirlpool");
+    create_hash_fn::<Whirlpool>(conn, "whirlpool", flags)
+}
@@ -1,10 +1,156 @@
-use noncrypto_digests::{Xxh32, Xxh3_128, Xxh3_64, Xxh64};
+use std::panic::{RefUnwindSafe, UnwindSafe};
 
+use digest::{FixedOutput, Update};
+use noncrypto_digests::{
+    HashWrapper, Xxh32, Xxh32Hasher, Xxh3Hasher, Xxh3_128, Xxh3_64, Xxh64, Xxh64Hasher,
+};
+use xxhash_rust::xxh3::{xxh3_128_with_secret, xxh3_64_with_secret};
+
+use crate::param::{create_param_hash_fn, ParamDigest, Params};
+use crate::rusqlite::functions::FunctionFlags;
 use crate::rusqlite::{Connection, Result};
+use crate::scalar::{create_hash_fn, DIRECT_ONLY_FLAGS};
+
+/// Size of the default XXH3 secret in bytes, the only size supported by the streaming hasher.
+const DEFAULT_SECRET_LEN: usize = 192;
+/// The shortest XXH3 secret, `XXH3_SECRET_SIZE_MIN` of the reference implementation.
+const MIN_SECRET_LEN: usize = 136;
+
+/// xxHash hasher that can be created with a custom seed.
+pub(crate) trait SeededHasher {
+    fn with_seed(params: &Params) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+impl SeededHasher for Xxh32Hasher {
+    fn with_seed(params: &Params) -> Result<Self> {
+        Ok(Self::new(params.u32(0, "seed")?))
+    }
+}
+
+impl SeededHasher for Xxh64Hasher {
+    fn with_seed(params: &Params) -> Result<Self> {
+        Ok(Self::new(params.u64(0, "seed")?))
+    }
+}
+
+impl SeededHasher for Xxh3Hasher {
+    fn with_seed(params: &Params) -> Result<Self> {
+        Ok(Self::with_seed(params.u64(0, "seed")?))
+    }
+}
+
+/// xxHash with the seed as the first parameter.
+#[derive(Clone)]
+pub(crate) struct Seeded<T>(T);
+
+impl<T> ParamDigest for Seeded<T>
+where
+    T: HashWrapper + Update + FixedOutput + Clone + UnwindSafe + RefUnwindSafe + 'static,
+    T::Hasher: SeededHasher,
+{
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        Ok(Self(T::from_hasher(T::Hasher::with_seed(params)?)))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.0.finalize_fixed().to_vec()
+    }
+}
+
+/// XXH3 variant that can hash the whole input at once with a secret of any valid size.
+pub(crate) trait SecretHasher {
+    fn hash_with_secret(data: &[u8], secret: &[u8]) -> Vec<u8>;
+}
+
+impl SecretHasher for Xxh3_64 {
+    fn hash_with_secret(data: &[u8], secret: &[u8]) -> Vec<u8> {
+        xxh3_64_with_secret(data, secret).to_be_bytes().to_vec()
+    }
+}
+
+impl SecretHasher for Xxh3_128 {
+    fn hash_with_secret(data: &[u8], secret: &[u8]) -> Vec<u8> {
+        xxh3_128_with_secret(data, secret).to_be_bytes().to_vec()
+    }
+}
+
+/// XXH3 with the custom secret as the first parameter.
+/// The streaming hasher only supports secrets of the default size, so the input is buffered for other sizes.
+#[derive(Clone)]
+pub(crate) enum Secret<T> {
+    Streaming(T),
+    Buffered { secret: Vec<u8>, data: Vec<u8> },
+}
+
+impl<T> ParamDigest for Secret<T>
+where
+    T: HashWrapper<Hasher = Xxh3Hasher>
+        + SecretHasher
+        + Update
+        + FixedOutput
+        + Clone
+        + UnwindSafe
+        + RefUnwindSafe
+        + 'static,
+{
+    const PARAMS: usize = 1;
+
+    fn new(params: &Params) -> Result<Self> {
+        let secret = params.bytes(0, "secret")?;
+        if let Ok(secret) = <[u8; DEFAULT_SECRET_LEN]>::try_from(secret) {
+            Ok(Self::Streaming(T::from_hasher(Xxh3Hasher::with_secret(
+                secret,
+            ))))
+        } else if secret.len() >= MIN_SECRET_LEN {
+            Ok(Self::Buffered {
+                secret: secret.to_vec(),
+                data: Vec::new(),
+            })
+        } else {
+            Err(params.error(
+                0,
+                "secret",
+                format_args!(
+                    "must be at least {MIN_SECRET_LEN} bytes, got {}",
+                    secret.len()
+                ),
+            ))
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Streaming(hasher) => hasher.update(data),
+            Self::Buffered { data: buffer, .. } => buffer.extend_from_slice(data),
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Self::Streaming(hasher) => hasher.finalize_fixed().to_vec(),
+            Self::Buffered { secret, data } => T::hash_with_secret(&data, &secret),
+        }
+    }
+}
 
 /// Register `xxh32`, `xxh64`, `xxh3_64`, `xxh3_128`, `xxh3_64` SQL functions with the given `SQLite` connection.
 /// The functions use [Rust xxHash implementation](https://github.com/DoumanAsh/xxhash-rust) to compute the hash of the argument(s) using zero as the seed value.
 ///
+/// The seeded variants `xxh32_seeded(seed, ...)`, `xxh64_seeded(seed, ...)`, `xxh3_64_seeded(seed, ...)`,
+/// and `xxh3_128_seeded(seed, ...)` take the integer seed as the first argument. The `xxh32` seed must be
+/// between 0 and 4294967295, while the 64-bit seeds can be any integer, with negative values used as their two's complement.
+/// The `xxh3_64_secret(secret, ...)` and `xxh3_128_secret(secret, ...)` functions take a custom XXH3 secret,
+/// which must be a blob of at least 136 bytes, like `XXH3_SECRET_SIZE_MIN` of the reference implementation.
+/// The input is hashed as it arrives with the secrets of the default 192 bytes, and buffered with the other sizes.
+///
 /// # Example
 ///
 /// ```
@@ -27,12 +173,25 @@ use crate::rusqlite::{Connection, Result};
 /// let hash: Vec<u8> = db.query_row("SELECT xxh3_128('hello')", [], |r| r.get(0))?;
 /// let expected = b"\xb5\xe9\xc1\xad\x07\x1b\x3e\x7f\xc7\x79\xcf\xaa\x5e\x52\x38\x18";
 /// assert_eq!(hash, expected);
+/// let hash: Vec<u8> = db.query_row("SELECT xxh64_seeded(42, 'hello')", [], |r| r.get(0))?;
+/// let expected = b"\xC3\x62\x9E\x63\x18\xD5\x39\x32";
+/// assert_eq!(hash, expected);
 /// # Ok(())
 /// # }
 /// ```
 pub fn register_xxhash_functions(conn: &Connection) -> Result<()> {
-    crate::scalar::create_hash_fn::<Xxh32>(conn, "xxh32")?;
-    crate::scalar::create_hash_fn::<Xxh64>(conn, "xxh64")?;
-    crate::scalar::create_hash_fn::<Xxh3_64>(conn, "xxh3_64")?;
-    crate::scalar::create_hash_fn::<Xxh3_128>(conn, "xxh3_128")
+    register(conn, DIRECT_ONLY_FLAGS)
+}
+
+pub(crate) fn register(conn: &Connection, flags: FunctionFlags) -> Result<()> {
+    create_hash_fn::<Xxh32>(conn, "xxh32", flags)?;
+    create_hash_fn::<Xxh64>(conn, "xxh64", flags)?;
+    create_hash_fn::<Xxh3_64>(conn, "xxh3_64", flags)?;
+    create_hash_fn::<Xxh3_128>(conn, "xxh3_128", flags)?;
+    create_param_hash_fn::<Seeded<Xxh32>>(conn, "xxh32_seeded", flags)?;
+    create_param_hash_fn::<Seeded<Xxh64>>(conn, "xxh64_seeded", flags)?;
+    create_param_hash_fn::<Seeded<Xxh3_64>>(conn, "xxh3_64_seeded", flags)?;
+    create_param_hash_fn::<Seeded<Xxh3_128>>(conn, "xxh3_128_seeded", flags)?;
+    create_param_hash_fn::<Secret<Xxh3_64>>(conn, "xxh3_64_secret", flags)?;
+    create_param_hash_fn::<Secret<Xxh3_128>>(conn, "xxh3_128_secret", flags)
 }
@@ -49,6 +49,16 @@ fn hasher() {
     assert_snapshot!(hash_hex::<sha2::Sha384>("test".as_bytes()), @"768412320F7B0AA5812FCE428DC4706B3CAE50E02A64CAA16A782249BFE8EFC4B7EF1CCB126255D196047DFEDF17A0A9");
     #[cfg(feature = "sha512")]
     assert_snapshot!(hash_hex::<sha2::Sha512>("test".as_bytes()), @"EE26B0DD4AF7E749AA1A8EE3C10AE9923F618980772E473F8819A5D4940E0DB27AC185F8A0E1D5F84F88BC887FD67B143732C304CC5FA9AD8E6F57F50028A8FF");
+    #[cfg(feature = "sha512_224")]
+    assert_snapshot!(hash_hex::<sha2::Sha512_224>("test".as_bytes()), @"06001BF08DFB17D2B54925116823BE230E98B5C6C278303BC4909A8C");
+    #[cfg(feature = "sha512_256")]
+    assert_snapshot!(hash_hex::<sha2::Sha512_256>("test".as_bytes()), @"3D37FE58435E0D87323DEE4A2C1B339EF954DE63716EE79F5747F94D974F913F");
+    #[cfg(feature = "sm3")]
+    assert_snapshot!(hash_hex::<sm3::Sm3>("test".as_bytes()), @"55E12E91650D2FEC56EC74E1D3E4DDBFCE2EF3A65890C2A19ECF88A307E76A23");
+    #[cfg(feature = "streebog256")]
+    assert_snapshot!(hash_hex::<streebog::Streebog256>("test".as_bytes()), @"12A50838191B5504F1E5F2FD078714CF6B592B9D29AF99D0B10D8D02881C3857");
+    #[cfg(feature = "streebog512")]
+    assert_snapshot!(hash_hex::<streebog::Streebog512>("test".as_bytes()), @"7200BF5DEA560F0D7960D07FDC8874AD9F3B86ECE2E45F5502AE2E176F2C928E0E581152281F5AEE818318BED7CBE6AA69999589234723CEB33175598365B5C8");
     #[cfg(feature = "blake3")]
     assert_snapshot!(hash_hex::<blake3::Hasher>("test".as_bytes()), @"BA80A53F981C4D0D6A2797BEEA0D8B8A7A0B1E8B6A27E4F7A0E3C6C7E6F7A0E3C6C7E6F7A0E3C6C7E6F7A0E3C6C7E6F");
     #[cfg(feature = "fnv")]
@@ -142,12 +152,39 @@ hash_macros!(
     "sha256" sha256 sha2::Sha256,
     "sha384" sha384 sha2::Sha384,
     "sha512" sha512 sha2::Sha512,
+    "sha512_224" sha512_224 sha2::Sha512_224,
+    "sha512_256" sha512_256 sha2::Sha512_256,
+    "sha3" sha3_224 sha3::Sha3_224,
+    "sha3" sha3_256 sha3::Sha3_256,
+    "sha3" sha3_384 sha3::Sha3_384,
+    "sha3" sha3_512 sha3::Sha3_512,
+    "keccak" keccak256 sha3::Keccak256,
+    "ascon" ascon_hash256 ascon_hash256::AsconHash256,
+    "sm3" sm3 sm3::Sm3,
+    "streebog256" streebog256 streebog::Streebog256,
+    "streebog512" streebog512 streebog::Streebog512,
+    "blake2" blake2b512 blake2::Blake2b512,
+    "blake2" blake2s256 blake2::Blake2s256,
     "blake3" blake3 blake3::Hasher,
+    "crc" crc32 sqlite_hashes::Crc32,
+    "crc" crc32c sqlite_hashes::Crc32c,
+    "crc" crc64_ecma sqlite_hashes::Crc64Ecma,
+    "crc" crc64_xz sqlite_hashes::Crc64Xz,
+    "crc" adler32 sqlite_hashes::Adler32,
     "fnv" fnv1a noncrypto_digests::Fnv,
+    "fnv" fnv1a_32 sqlite_hashes::Fnv1a_32,
+    "fnv" fnv1a_128 sqlite_hashes::Fnv1a_128,
+    "fnv" fnv1_32 sqlite_hashes::Fnv1_32,
+    "fnv" fnv1_64 sqlite_hashes::Fnv1_64,
+    "fnv" fnv1_128 sqlite_hashes::Fnv1_128,
     "xxhash" xxh32 noncrypto_digests::Xxh32,
     "xxhash" xxh64 noncrypto_digests::Xxh64,
     "xxhash" xxh3_64 noncrypto_digests::Xxh3_64,
     "xxhash" xxh3_128 noncrypto_digests::Xxh3_128,
+    "md4" md4 md4::Md4,
+    "ripemd160" ripemd160 ripemd::Ripemd160,
+    "tiger" tiger tiger::Tiger,
+    "whirlpool" whirlpool whirlpool::Whirlpool,
 );
 
 macro_rules! test_all {
@@ -163,12 +200,39 @@ macro_rules! test_all {
         sha256!( $conn.$func(&format!("sha256{suffix}")), $($any)* );
         sha384!( $conn.$func(&format!("sha384{suffix}")), $($any)* );
         sha512!( $conn.$func(&format!("sha512{suffix}")), $($any)* );
+        sha512_224!( $conn.$func(&format!("sha512_224{suffix}")), $($any)* );
+        sha512_256!( $conn.$func(&format!("sha512_256{suffix}")), $($any)* );
+        sha3_224!( $conn.$func(&format!("sha3_224{suffix}")), $($any)* );
+        sha3_256!( $conn.$func(&format!("sha3_256{suffix}")), $($any)* );
+        sha3_384!( $conn.$func(&format!("sha3_384{suffix}")), $($any)* );
+        sha3_512!( $conn.$func(&format!("sha3_512{suffix}")), $($any)* );
+        keccak256!( $conn.$func(&format!("keccak256{suffix}")), $($any)* );
+        ascon_hash256!( $conn.$func(&format!("ascon_hash256{suffix}")), $($any)* );
+        sm3!( $conn.$func(&format!("sm3{suffix}")), $($any)* );
+        streebog256!( $conn.$func(&format!("streebog256{suffix}")), $($any)* );
+        streebog512!( $conn.$func(&format!("streebog512{suffix}")), $($any)* );
+        blake2b512!( $conn.$func(&format!("blake2b512{suffix}")), $($any)* );
+        blake2s256!( $conn.$func(&format!("blake2s256{suffix}")), $($any)* );
         blake3!( $conn.$func(&format!("blake3{suffix}")), $($any)* );
+        crc32!( $conn.$func(&format!("crc32{suffix}")), $($any)* );
+        crc32c!( $conn.$func(&format!("crc32c{suffix}")), $($any)* );
+        crc64_ecma!( $conn.$func(&format!("crc64_ecma{suffix}")), $($any)* );
+        crc64_xz!( $conn.$func(&format!("crc64_xz{suffix}")), $($any)* );
+        adler32!( $conn.$func(&format!("adler32{suffix}")), $($any)* );
         fnv1a!( $conn.$func(&format!("fnv1a{suffix}")), $($any)* );
+        fnv1a_32!( $conn.$func(&format!("fnv1a_32{suffix}")), $($any)* );
+        fnv1a_128!( $conn.$func(&format!("fnv1a_128{suffix}")), $($any)* );
+        fnv1_32!( $conn.$func(&format!("fnv1_32{suffix}")), $($any)* );
+        fnv1_64!( $conn.$func(&format!("fnv1_64{suffix}")), $($any)* );
+        fnv1_128!( $conn.$func(&format!("fnv1_128{suffix}")), $($any)* );
         xxh32!( $conn.$func(&format!("xxh32{suffix}")), $($any)* );
         xxh64!( $conn.$func(&format!("xxh64{suffix}")), $($any)* );
         xxh3_64!( $conn.$func(&format!("xxh3_64{suffix}")), $($any)* );
         xxh3_128!( $conn.$func(&format!("xxh3_128{suffix}")), $($any)* );
+        md4!( $conn.$func(&format!("md4{suffix}")), $($any)* );
+        ripemd160!( $conn.$func(&format!("ripemd160{suffix}")), $($any)* );
+        tiger!( $conn.$func(&format!("tiger{suffix}")), $($any)* );
+        whirlpool!( $conn.$func(&format!("whirlpool{suffix}")), $($any)* );
     }};
 }
 
@@ -145,3 +145,335 @@ fn concat_sequence_hex() {
         hex(expected)
     );
 }
+
+#[test]
+#[cfg(all(feature = "hmac", feature = "sha256"))]
+fn hmac_concat() {
+    let c = Conn::new();
+    let sql = "SELECT hex(hmac_sha256_concat('Jefe', v)) FROM (SELECT 'what do ya ' AS v UNION ALL SELECT NULL UNION ALL SELECT 'want for nothing?')";
+    assert_eq!(
+        c.sql::<String>(sql).unwrap(),
+        "5BDCC146BF60754E6A042426089575C75A003F089D2739839DEC58B964EC3843"
+    );
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>("hmac_sha256_concat('key', cast(v as text))"),
+        Ok(None)
+    );
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            c.select::<String>("hmac_sha256_concat_hex('key', '123', '45')")
+                .unwrap(),
+            "AB99A81F96D56F3B99596E3168B1ADE13E02AB0AAE08898B8AA4E3377C9E29D1"
+        );
+    }
+    assert!(c
+        .select::<Vec<u8>>("hmac_sha256_concat(NULL, 'a')")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "shake")]
+fn shake_concat() {
+    let c = Conn::new();
+    let expected = "5881092DD818BF5CF8A3DDB793FBCBA74097D5C526A6D35F97B83351940F2CC8";
+    let sql = "SELECT hex(shake128_concat(32, v)) FROM (SELECT 'a' AS v UNION ALL SELECT NULL UNION ALL SELECT 'bc')";
+    assert_eq!(c.sql::<String>(sql).unwrap(), expected);
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>("shake128_concat(32, cast(v as text))"),
+        Ok(None)
+    );
+    let sql = "SELECT shake128_concat(8, v_text) FROM (SELECT v_text FROM tbl ORDER BY v_text)";
+    assert_eq!(c.sql::<Vec<u8>>(sql).unwrap().len(), 8);
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            c.select::<String>("shake128_concat_hex(32, 'a', 'bc')")
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            c.select::<String>("shake128_concat_hex(32, NULL)").unwrap(),
+            ""
+        );
+    }
+    assert!(c.select::<Vec<u8>>("shake128_concat(0, 'a')").is_err());
+}
+
+#[test]
+#[cfg(feature = "kmac")]
+fn kmac_concat() {
+    let c = Conn::new();
+    let key = "x'404142434445464748494A4B4C4D4E4F505152535455565758595A5B5C5D5E5F'";
+    let sql = format!(
+        "SELECT hex(kmac128_concat({key}, 'My Tagged Application', v)) FROM (SELECT x'0001' AS v UNION ALL SELECT x'0203')"
+    );
+    assert_eq!(
+        c.sql::<String>(&sql).unwrap(),
+        "3B1FBA963CD8B0B59E8C1A6D71888B7143651AF8BA0A7070C0979E2811324AA5"
+    );
+    assert!(c
+        .select::<Vec<u8>>(&format!("kmac128_concat({key}, NULL, 'a')"))
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "k12")]
+fn k12_concat() {
+    let c = Conn::new();
+    let sql = "SELECT hex(kt128_concat(32, x'000102', v)) FROM (SELECT x'FF' AS v UNION ALL SELECT NULL UNION ALL SELECT x'FFFF')";
+    assert_eq!(
+        c.sql::<String>(sql).unwrap(),
+        c.select::<String>("hex(kt128(32, x'000102', x'FFFFFF'))")
+            .unwrap()
+    );
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>("kt256_concat(64, '', cast(v as text))"),
+        Ok(None)
+    );
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            c.select::<String>("kt128_concat_hex(8, '', '')").unwrap(),
+            "1AC2D450FC3B4205"
+        );
+    }
+    assert!(c.select::<Vec<u8>>("kt128_concat(8, NULL, 'a')").is_err());
+}
+
+#[test]
+#[cfg(feature = "turboshake")]
+fn turboshake_concat() {
+    let c = Conn::new();
+    let sql =
+        "SELECT hex(turboshake128_concat(32, 31, v)) FROM (SELECT '' AS v UNION ALL SELECT NULL)";
+    assert_eq!(
+        c.sql::<String>(sql).unwrap(),
+        "1E415F1C5983AFF2169217277D17BB538CD945A397DDEC541F1CE41AF2C1B74C"
+    );
+    let sql =
+        "SELECT turboshake256_concat(200, 6, v_text) FROM (SELECT v_text FROM tbl ORDER BY v_text)";
+    assert_eq!(c.sql::<Vec<u8>>(sql).unwrap().len(), 200);
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>("turboshake128_concat(32, 31, cast(v as text))"),
+        Ok(None)
+    );
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            c.select::<String>("turboshake128_concat_hex(4, 31, '')")
+                .unwrap(),
+            "1E415F1C"
+        );
+    }
+    assert!(c
+        .select::<Vec<u8>>("turboshake128_concat(32, 128, 'a')")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "ascon")]
+fn ascon_concat() {
+    let c = Conn::new();
+    let sql = "SELECT hex(ascon_cxof128_concat(64, x'10', v)) FROM (SELECT x'00' AS v UNION ALL SELECT NULL UNION ALL SELECT x'0102')";
+    assert_eq!(
+        c.sql::<String>(sql).unwrap(),
+        "EC3AF60C67B5DBD275842785281ABE910E251504F2CD807BB9A137A310BA022763527D41EB9875D7D2CA56ABF568FFF4533A91259A4F6FB962F4C7FCFD6E6DD9"
+    );
+    let sql =
+        "SELECT hex(ascon_xof128_concat(32, v)) FROM (SELECT x'0001' AS v UNION ALL SELECT x'02')";
+    assert_eq!(
+        c.sql::<String>(sql).unwrap(),
+        "9C96F31C3E7BDFDC5EF6BA836F760A0D6548D94DD0A512033022C9242E8BA916"
+    );
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>("ascon_xof128_concat(32, cast(v as text))"),
+        Ok(None)
+    );
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            c.select::<String>("ascon_xof128_concat_hex(4, '')")
+                .unwrap(),
+            "473D5E61"
+        );
+    }
+    assert!(c
+        .select::<Vec<u8>>("ascon_cxof128_concat(8, 1, 'a')")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "blake2")]
+fn blake2_concat() {
+    let c = Conn::new();
+    let sql = "SELECT hex(blake2b_keyed_concat(8, 'key', 'salt', 'app-v1', v)) FROM (SELECT 'hel' AS v UNION ALL SELECT NULL UNION ALL SELECT 'lo')";
+    assert_eq!(c.sql::<String>(sql).unwrap(), "505EAF8CA16CEA02");
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>("blake2s_concat(16, cast(v as text))"),
+        Ok(None)
+    );
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            c.select::<String>("blake2s_concat_hex(8, 'hel', 'lo')")
+                .unwrap(),
+            "5193815F19644C56"
+        );
+    }
+    assert!(c
+        .select::<Vec<u8>>("blake2b_keyed_concat(8, 'key', 'salt', NULL, 'a')")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "blake3")]
+fn blake3_modes_concat() {
+    let c = Conn::new();
+    let sql = "SELECT hex(blake3_keyed_concat('whats the Elvish word for friend', v)) FROM (SELECT NULL AS v UNION ALL SELECT x'00')";
+    assert_eq!(
+        c.sql::<String>(sql).unwrap(),
+        "6D7878DFFF2F485635D39013278AE14F1454B8C0A3A2D34BC1AB38228A80C95B"
+    );
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>("blake3_derive_key_concat('ctx', cast(v as text))"),
+        Ok(None)
+    );
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            c.select::<String>("blake3_xof_concat_hex(4, 'hel', 'lo')")
+                .unwrap(),
+            "EA8F163D"
+        );
+    }
+    assert!(c
+        .select::<Vec<u8>>("blake3_keyed_concat(x'00', 'a')")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "xxhash")]
+fn xxhash_seeded_concat() {
+    let c = Conn::new();
+    let sql = "SELECT hex(xxh64_seeded_concat(42, v)) FROM (SELECT 'hel' AS v UNION ALL SELECT NULL UNION ALL SELECT 'lo')";
+    assert_eq!(c.sql::<String>(sql).unwrap(), "C3629E6318D53932");
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>("xxh3_64_seeded_concat(1, cast(v as text))"),
+        Ok(None)
+    );
+    let secret = (0..192_u32).fold(String::new(), |mut s, i| {
+        write!(s, "{:02X}", (i * 7 + 3) % 256).unwrap();
+        s
+    });
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            c.select::<String>(&format!(
+                "xxh3_128_secret_concat_hex(x'{secret}', 'hel', 'lo')"
+            ))
+            .unwrap(),
+            "A863330AA71CB38E123FC2971046B843"
+        );
+        // Secrets other than 192 bytes buffer the input
+        assert_eq!(
+            c.select::<String>(&format!(
+                "xxh3_64_secret_concat_hex(x'{}', printf('%.400c', 'x'), printf('%.600c', 'x'))",
+                &secret[..272]
+            ))
+            .unwrap(),
+            "462BDB3E4E465A59"
+        );
+    }
+    assert!(c
+        .select::<Vec<u8>>("xxh3_64_secret_concat(x'00', 'a')")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "crc")]
+fn crc_int_concat() {
+    let c = Conn::new();
+    let sql = "SELECT crc32_int_concat(v) FROM (SELECT '1234' AS v UNION ALL SELECT NULL UNION ALL SELECT '56789')";
+    assert_eq!(c.sql::<i64>(sql).unwrap(), 0xCBF4_3926);
+    let sql = "SELECT hex(crc32c_concat(v)) FROM (SELECT '1234' AS v UNION ALL SELECT '56789')";
+    assert_eq!(c.sql::<String>(sql).unwrap(), "E3069283");
+    assert_eq!(
+        c.seq_0::<Option<i64>>("adler32_int_concat(cast(v as text))"),
+        Ok(None)
+    );
+    assert!(c.select::<i64>("crc64_xz_int_concat(1)").is_err());
+}
+
+#[test]
+#[cfg(feature = "murmur3")]
+fn murmur3_concat() {
+    let c = Conn::new();
+    let sql = "SELECT murmur3_32_int_concat(42, v) FROM (SELECT 'hel' AS v UNION ALL SELECT NULL UNION ALL SELECT 'lo')";
+    assert_eq!(c.sql::<i64>(sql).unwrap(), -488_910_111);
+    let sql = "SELECT hex(murmur3_128_concat(0, v)) FROM (SELECT 'hel' AS v UNION ALL SELECT 'lo')";
+    assert_eq!(
+        c.sql::<String>(sql).unwrap(),
+        "029BBD41B3A7D8CB191DAE486A901E5B"
+    );
+    assert_eq!(
+        c.seq_0::<Option<i64>>("murmur3_32_int_concat(0, cast(v as text))"),
+        Ok(None)
+    );
+    assert!(c.select::<i64>("murmur3_32_int_concat(0, 1)").is_err());
+    assert!(c
+        .select::<i64>("murmur3_32_int_concat(-1, 'a')")
+        .unwrap_err()
+        .to_string()
+        .contains(
+            "murmur3_32_int_concat: argument 1 (seed) must be between 0 and 4294967295, got -1"
+        ));
+}
+
+#[test]
+#[cfg(feature = "siphash")]
+fn siphash_concat() {
+    let c = Conn::new();
+    let key = "x'000102030405060708090A0B0C0D0E0F'";
+    let sql = format!("SELECT siphash24_int_concat({key}, v) FROM (SELECT x'0001' AS v UNION ALL SELECT NULL UNION ALL SELECT x'0203')");
+    assert_eq!(
+        c.sql::<i64>(&sql).unwrap(),
+        i64::from_be_bytes(0xCF27_94E0_2771_87B7_u64.to_be_bytes())
+    );
+    let sql = format!("SELECT hex(siphash128_concat({key}, v)) FROM (SELECT x'000102' AS v UNION ALL SELECT x'03040506070809')");
+    assert_eq!(
+        c.sql::<String>(&sql).unwrap(),
+        "00110DC378146956C95447D3F3D0FBBA"
+    );
+    assert_eq!(
+        c.seq_0::<Option<i64>>(&format!("siphash13_int_concat({key}, cast(v as text))")),
+        Ok(None)
+    );
+    assert!(c.select::<i64>("siphash13_int_concat(x'00', 'a')").is_err());
+}
+
+#[test]
+#[cfg(feature = "highwayhash")]
+fn highwayhash_concat() {
+    let c = Conn::new();
+    let key = "x'000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F'";
+    let sql = format!("SELECT hex(highwayhash64_concat({key}, v)) FROM (SELECT x'00' AS v UNION ALL SELECT NULL UNION ALL SELECT x'0102')");
+    assert_eq!(c.sql::<String>(&sql).unwrap(), "803D468AABEF6B5C");
+    assert_eq!(
+        c.seq_0::<Option<Vec<u8>>>(&format!("highwayhash128_concat({key}, cast(v as text))")),
+        Ok(None)
+    );
+    assert!(c
+        .select::<Vec<u8>>("highwayhash256_concat(x'00', 'a')")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "fasthash")]
+fn fasthash_concat() {
+    let c = Conn::new();
+    let sql = "SELECT hex(rapidhash_v3_concat(0, v)) FROM (SELECT 'hello ' AS v UNION ALL SELECT NULL UNION ALL SELECT 'world')";
+    assert_eq!(c.sql::<String>(sql).unwrap(), "2F27CB27D5240940");
+    let sql = "SELECT wyhash_final4_int_concat(3, v) FROM (SELECT 'message ' AS v UNION ALL SELECT 'digest')";
+    assert_eq!(
+        c.sql::<i64>(sql).unwrap(),
+        i64::from_be_bytes(0x8619_1240_89A3_A16B_u64.to_be_bytes())
+    );
+    assert_eq!(
+        c.seq_0::<Option<i64>>("komihash_v5_int_concat(0, cast(v as text))"),
+        Ok(None)
+    );
+    assert!(c.select::<i64>("komihash_v5_int_concat(0, 1)").is_err());
+}
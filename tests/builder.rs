@@ -0,0 +1,207 @@
+#![cfg(all(
+    feature = "hex",
+    feature = "aggregate",
+    feature = "sha256",
+    feature = "md5"
+))]
+#![allow(clippy::unwrap_used)]
+
+use rusqlite::functions::FunctionFlags;
+use rusqlite::types::FromSql;
+use rusqlite::{Connection, Result};
+use sqlite_hashes::{HashFunctions, Variants};
+
+// SAFETY: This test-only initializer only attempts to initialize env_logger; failures (already initialized) are ignored.
+#[ctor::ctor(unsafe)]
+fn init() {
+    let _ = env_logger::builder().is_test(true).try_init();
+}
+
+const SHA256_HELLO: &str = "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824";
+const MD5_HELLO: &str = "5D41402ABC4B2A76B9719D911017C592";
+
+fn select<T: FromSql>(db: &Connection, expr: &str) -> Result<T> {
+    db.query_row(&format!("SELECT {expr}"), [], |r| r.get(0))
+}
+
+#[test]
+fn defaults() {
+    let db = Connection::open_in_memory().unwrap();
+    HashFunctions::builder()
+        .algorithm::<sha2::Sha256>()
+        .register(&db)
+        .unwrap();
+
+    assert_eq!(
+        select::<String>(&db, "hex(sha256('hello'))").unwrap(),
+        SHA256_HELLO
+    );
+    assert_eq!(
+        select::<String>(&db, "sha256_hex('hello')").unwrap(),
+        SHA256_HELLO
+    );
+    assert_eq!(
+        select::<String>(&db, "hex(sha256_concat('hello'))").unwrap(),
+        SHA256_HELLO
+    );
+    assert_eq!(
+        select::<String>(&db, "sha256_concat_hex('hello')").unwrap(),
+        SHA256_HELLO
+    );
+    assert!(select::<String>(&db, "md5_hex('hello')").is_err());
+
+    // Default flags match the `register_*_functions` ones
+    let err = db.execute_batch("CREATE TABLE t(v TEXT, h BLOB GENERATED ALWAYS AS (sha256(v)));");
+    assert!(err.is_err());
+}
+
+#[test]
+fn variants_and_names() {
+    let db = Connection::open_in_memory().unwrap();
+    HashFunctions::builder()
+        .algorithm::<sha2::Sha256>()
+        .algorithm_as::<md5::Md5>("legacy_md5")
+        .variants(Variants::HEX | Variants::CONCAT_HEX)
+        .prefix("h_")
+        .register(&db)
+        .unwrap();
+
+    assert_eq!(
+        select::<String>(&db, "h_sha256_hex('hello')").unwrap(),
+        SHA256_HELLO
+    );
+    assert_eq!(
+        select::<String>(&db, "h_sha256_concat_hex('hello')").unwrap(),
+        SHA256_HELLO
+    );
+    assert_eq!(
+        select::<String>(&db, "h_legacy_md5_hex('hello')").unwrap(),
+        MD5_HELLO
+    );
+    assert!(select::<Vec<u8>>(&db, "h_sha256('hello')").is_err());
+    assert!(select::<Vec<u8>>(&db, "h_sha256_concat('hello')").is_err());
+    assert!(select::<String>(&db, "sha256_hex('hello')").is_err());
+    assert!(select::<String>(&db, "h_md5_hex('hello')").is_err());
+    assert!(select::<String>(&db, "h_hmac_sha256_hex('key', 'hello')").is_err());
+}
+
+#[test]
+#[cfg(feature = "hmac")]
+fn hmac_variants() {
+    let db = Connection::open_in_memory().unwrap();
+    HashFunctions::builder()
+        .algorithm::<sha2::Sha256>()
+        .variants(Variants::HEX | Variants::HMAC)
+        .prefix("h_")
+        .register(&db)
+        .unwrap();
+
+    assert_eq!(
+        select::<String>(&db, "h_hmac_sha256_hex('key', '12345')").unwrap(),
+        "AB99A81F96D56F3B99596E3168B1ADE13E02AB0AAE08898B8AA4E3377C9E29D1"
+    );
+    assert_eq!(
+        select::<String>(&db, "h_sha256_hex('hello')").unwrap(),
+        SHA256_HELLO
+    );
+    assert!(select::<Vec<u8>>(&db, "h_hmac_sha256('key', 'hello')").is_err());
+    assert!(select::<String>(&db, "hmac_h_sha256_hex('key', 'hello')").is_err());
+}
+
+#[test]
+fn trunc_variants() {
+    let db = Connection::open_in_memory().unwrap();
+    HashFunctions::builder()
+        .algorithm::<sha2::Sha256>()
+        .algorithm_as::<md5::Md5>("legacy_md5")
+        .variants(Variants::HEX | Variants::CONCAT_HEX | Variants::TRUNC)
+        .prefix("h_")
+        .register(&db)
+        .unwrap();
+
+    assert_eq!(
+        select::<String>(&db, "h_sha256_trunc_hex(4, 'hello')").unwrap(),
+        &SHA256_HELLO[..8]
+    );
+    assert_eq!(
+        select::<String>(&db, "h_legacy_md5_trunc_concat_hex(2, 'hello')").unwrap(),
+        &MD5_HELLO[..4]
+    );
+    assert!(select::<Vec<u8>>(&db, "h_sha256_trunc(4, 'hello')").is_err());
+    assert!(select::<Vec<u8>>(&db, "h_sha256_trunc_concat(4, 'hello')").is_err());
+
+    let db = Connection::open_in_memory().unwrap();
+    HashFunctions::builder()
+        .algorithm::<sha2::Sha256>()
+        .variants(Variants::SCALAR | Variants::HEX)
+        .register(&db)
+        .unwrap();
+    assert!(select::<String>(&db, "sha256_trunc_hex(4, 'hello')").is_err());
+}
+
+#[test]
+fn no_variants() {
+    let db = Connection::open_in_memory().unwrap();
+    HashFunctions::builder()
+        .algorithm::<sha2::Sha256>()
+        .variants(Variants::NONE)
+        .register(&db)
+        .unwrap();
+    assert!(select::<Vec<u8>>(&db, "sha256('hello')").is_err());
+}
+
+#[test]
+fn flags() {
+    let db = Connection::open_in_memory().unwrap();
+    HashFunctions::builder()
+        .algorithm::<sha2::Sha256>()
+        .variants(Variants::HEX)
+        .flags(
+            FunctionFlags::SQLITE_UTF8
+                | FunctionFlags::SQLITE_DETERMINISTIC
+                | FunctionFlags::SQLITE_INNOCUOUS,
+        )
+        .register(&db)
+        .unwrap();
+    db.execute_batch(
+        "CREATE TABLE t(v TEXT, h TEXT GENERATED ALWAYS AS (sha256_hex(v)));
+         INSERT INTO t(v) VALUES ('hello');",
+    )
+    .unwrap();
+    assert_eq!(select::<String>(&db, "h FROM t").unwrap(), SHA256_HELLO);
+}
+
+#[test]
+fn multiple_connections() {
+    let read_only = HashFunctions::builder()
+        .algorithm::<sha2::Sha256>()
+        .variants(Variants::HEX);
+    let admin = read_only
+        .clone()
+        .algorithm::<md5::Md5>()
+        .variants(Variants::ALL);
+
+    let ro1 = Connection::open_in_memory().unwrap();
+    let ro2 = Connection::open_in_memory().unwrap();
+    let adm = Connection::open_in_memory().unwrap();
+    read_only.register(&ro1).unwrap();
+    read_only.register(&ro2).unwrap();
+    admin.register(&adm).unwrap();
+
+    for db in [&ro1, &ro2] {
+        assert_eq!(
+            select::<String>(db, "sha256_hex('hello')").unwrap(),
+            SHA256_HELLO
+        );
+        assert!(select::<String>(db, "md5_hex('hello')").is_err());
+        assert!(select::<String>(db, "sha256_concat_hex('hello')").is_err());
+    }
+    assert_eq!(
+        select::<String>(&adm, "sha256_concat_hex('hello')").unwrap(),
+        SHA256_HELLO
+    );
+    assert_eq!(
+        select::<String>(&adm, "md5_hex('hello')").unwrap(),
+        MD5_HELLO
+    );
+}
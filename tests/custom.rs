@@ -0,0 +1,90 @@
+#![allow(clippy::unwrap_used)]
+
+use digest::consts::U4;
+use digest::{FixedOutput, HashMarker, Output, OutputSizeUser, Update};
+use rusqlite::types::FromSql;
+use rusqlite::{Connection, Result};
+use sqlite_hashes::{register_digest, NamedDigest};
+
+// SAFETY: This test-only initializer only attempts to initialize env_logger; failures (already initialized) are ignored.
+#[ctor::ctor(unsafe)]
+fn init() {
+    let _ = env_logger::builder().is_test(true).try_init();
+}
+
+/// Sum of all bytes as a big-endian u32, simple enough to verify by hand.
+#[derive(Clone, Default)]
+struct Sum32(u32);
+
+impl HashMarker for Sum32 {}
+
+impl OutputSizeUser for Sum32 {
+    type OutputSize = U4;
+}
+
+impl Update for Sum32 {
+    fn update(&mut self, data: &[u8]) {
+        self.0 = data
+            .iter()
+            .fold(self.0, |acc, v| acc.wrapping_add(u32::from(*v)));
+    }
+}
+
+impl FixedOutput for Sum32 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.0.to_be_bytes());
+    }
+}
+
+impl NamedDigest for Sum32 {
+    fn name() -> &'static str {
+        "sum32"
+    }
+}
+
+fn select<T: FromSql>(db: &Connection, expr: &str) -> Result<T> {
+    db.query_row(&format!("SELECT {expr}"), [], |r| r.get(0))
+}
+
+#[test]
+fn custom_digest() {
+    let db = Connection::open_in_memory().unwrap();
+    register_digest::<Sum32>(&db).unwrap();
+
+    assert_eq!(
+        select::<Vec<u8>>(&db, "sum32(x'01', 'a', NULL, x'ff')").unwrap(),
+        [0, 0, 1, 0x61]
+    );
+    assert_eq!(select::<Vec<u8>>(&db, "sum32('')").unwrap(), [0; 4]);
+    assert_eq!(select::<Option<Vec<u8>>>(&db, "sum32(NULL)").unwrap(), None);
+    assert!(select::<Vec<u8>>(&db, "sum32(1)").is_err());
+    assert!(select::<Vec<u8>>(&db, "sum32(0.42)").is_err());
+    assert!(select::<Vec<u8>>(&db, "sum32()").is_err());
+    assert_eq!(
+        select::<Vec<u8>>(&db, "sum32_trunc(2, x'01', 'a')").unwrap(),
+        [0, 0]
+    );
+    assert!(select::<Vec<u8>>(&db, "sum32_trunc(5, 'a')").is_err());
+    // Digests without a block size do not get the HMAC functions
+    assert!(select::<Vec<u8>>(&db, "hmac_sum32('key', 'a')").is_err());
+
+    if cfg!(feature = "hex") {
+        assert_eq!(
+            select::<String>(&db, "sum32_hex(x'ff01')").unwrap(),
+            "00000100"
+        );
+        assert_eq!(select::<String>(&db, "sum32_hex(NULL)").unwrap(), "");
+        assert_eq!(
+            select::<String>(&db, "sum32_trunc_hex(3, x'ff01')").unwrap(),
+            "000001"
+        );
+    }
+    if cfg!(feature = "aggregate") {
+        let sql = "sum32_concat(v) FROM (SELECT x'ff' AS v UNION ALL SELECT x'02')";
+        assert_eq!(select::<Vec<u8>>(&db, sql).unwrap(), [0, 0, 1, 1]);
+    }
+    if cfg!(all(feature = "aggregate", feature = "hex")) {
+        let sql = "sum32_concat_hex(v) FROM (SELECT NULL AS v)";
+        assert_eq!(select::<String>(&db, sql).unwrap(), "");
+    }
+}
@@ -0,0 +1,133 @@
+#![cfg(all(feature = "hex", feature = "sha256"))]
+#![allow(clippy::unwrap_used)]
+
+use rusqlite::Connection;
+use sqlite_hashes::{register_hash_functions, register_innocuous_hash_functions};
+
+// SAFETY: This test-only initializer only attempts to initialize env_logger; failures (already initialized) are ignored.
+#[ctor::ctor(unsafe)]
+fn init() {
+    let _ = env_logger::builder().is_test(true).try_init();
+}
+
+const HELLO: &str = "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824";
+const WORLD: &str = "486EA46224D1BB4FB680F34F7C9AD96A8F24EC88BE73EA8E5A6C65260E9CB8A7";
+
+fn innocuous() -> Connection {
+    let db = Connection::open_in_memory().unwrap();
+    register_innocuous_hash_functions(&db).unwrap();
+    db
+}
+
+fn direct_only() -> Connection {
+    let db = Connection::open_in_memory().unwrap();
+    register_hash_functions(&db).unwrap();
+    db
+}
+
+fn query(db: &Connection, sql: &str) -> Vec<String> {
+    let mut stmt = db.prepare(sql).unwrap();
+    stmt.query_map([], |r| r.get(0))
+        .unwrap()
+        .collect::<rusqlite::Result<_>>()
+        .unwrap()
+}
+
+const GENERATED: &str = "
+CREATE TABLE docs(body TEXT, digest TEXT GENERATED ALWAYS AS (sha256_hex(body)) STORED);
+INSERT INTO docs(body) VALUES ('hello');";
+
+const INDEX: &str = "
+CREATE TABLE docs(body TEXT);
+CREATE INDEX docs_digest ON docs(sha256_hex(body));
+INSERT INTO docs VALUES ('hello'), ('world');";
+
+const TRIGGER: &str = "
+CREATE TABLE docs(body TEXT, digest TEXT);
+CREATE TRIGGER docs_ins AFTER INSERT ON docs BEGIN
+    UPDATE docs SET digest = sha256_hex(NEW.body) WHERE rowid = NEW.rowid;
+END;
+INSERT INTO docs(body) VALUES ('hello');";
+
+const VIEW: &str = "
+CREATE TABLE docs(body TEXT);
+CREATE VIEW docs_view AS SELECT sha256_hex(body) AS digest FROM docs;
+INSERT INTO docs VALUES ('hello');";
+
+const CHECK: &str = "
+CREATE TABLE docs(body TEXT, digest TEXT CHECK (digest = sha256_hex(body)));";
+
+#[test]
+fn generated_column() {
+    let db = innocuous();
+    db.execute_batch(GENERATED).unwrap();
+    assert_eq!(query(&db, "SELECT digest FROM docs"), [HELLO]);
+}
+
+#[test]
+fn expression_index() {
+    let db = innocuous();
+    db.execute_batch(INDEX).unwrap();
+    let sql = format!("SELECT body FROM docs WHERE sha256_hex(body) = '{WORLD}'");
+    assert_eq!(query(&db, &sql), ["world"]);
+    let plan: String = db
+        .query_row(&format!("EXPLAIN QUERY PLAN {sql}"), [], |r| r.get(3))
+        .unwrap();
+    assert!(plan.contains("docs_digest"), "index is not used: {plan}");
+}
+
+#[test]
+fn trigger() {
+    let db = innocuous();
+    db.execute_batch(TRIGGER).unwrap();
+    assert_eq!(query(&db, "SELECT digest FROM docs"), [HELLO]);
+}
+
+#[test]
+fn view() {
+    let db = innocuous();
+    db.execute_batch(VIEW).unwrap();
+    assert_eq!(query(&db, "SELECT digest FROM docs_view"), [HELLO]);
+}
+
+#[test]
+fn check_constraint() {
+    let db = innocuous();
+    db.execute_batch(CHECK).unwrap();
+    db.execute(&format!("INSERT INTO docs VALUES ('hello', '{HELLO}')"), [])
+        .unwrap();
+    db.execute(&format!("INSERT INTO docs VALUES ('hello', '{WORLD}')"), [])
+        .unwrap_err();
+}
+
+#[test]
+fn direct_only_rejected() {
+    for sql in [GENERATED, INDEX, TRIGGER] {
+        assert!(direct_only().execute_batch(sql).is_err(), "{sql}");
+    }
+    let db = direct_only();
+    db.execute_batch(VIEW).unwrap();
+    db.query_row::<String, _, _>("SELECT digest FROM docs_view", [], |r| r.get(0))
+        .unwrap_err();
+}
+
+#[test]
+#[cfg(feature = "kdf")]
+fn pbkdf2_direct_only() {
+    let db = innocuous();
+    db.execute_batch(
+        "
+CREATE TABLE users(password TEXT);
+CREATE VIEW keys AS SELECT hex(pbkdf2('sha256', password, 'salt', 1, 4)) AS key FROM users;
+CREATE VIEW infos AS SELECT hex(hkdf('sha256', password, NULL, NULL, 4)) AS key FROM users;
+INSERT INTO users VALUES ('password');",
+    )
+    .unwrap();
+    let err = db
+        .query_row::<String, _, _>("SELECT key FROM keys", [], |r| r.get(0))
+        .unwrap_err();
+    assert!(err.to_string().contains("unsafe use of pbkdf2()"), "{err}");
+    assert_eq!(query(&db, "SELECT key FROM infos").len(), 1);
+    let sql = "SELECT hex(pbkdf2('sha256', password, 'salt', 1, 4)) FROM users";
+    assert_eq!(query(&db, sql), ["120FB6CF"]);
+}
@@ -45,15 +45,74 @@ test_hash() {
     test_one "SELECT ${hash}_concat_hex('12345');"   "$expected"
 }
 
-test_hash "md5"      "827CCB0EEA8A706C4C34A16891F84E7B"
-test_hash "sha1"     "8CB2237D0679CA88DB6464EAC60DA96345513964"
-test_hash "sha224"   "A7470858E79C282BC2F6ADFD831B132672DFD1224C1E78CBF5BCD057"
-test_hash "sha256"   "5994471ABB01112AFCC18159F6CC74B4F511B99806DA59B3CAF5A9C173CACFC5"
-test_hash "sha384"   "0FA76955ABFA9DAFD83FACCA8343A92AA09497F98101086611B0BFA95DBC0DCC661D62E9568A5A032BA81960F3E55D4A"
-test_hash "sha512"   "3627909A29C31381A071EC27F7C9CA97726182AED29A7DDD2E54353322CFB30ABB9E3A6DF2AC2C20FE23436311D678564D0C8D305930575F60E2D3D048184D79"
-test_hash "blake3"   "86F2D80ABE9C3F7B4A1A57A8D1130FA8DC08C81604833CE1212DC039B010D9E4"
-test_hash "fnv1a"    "E575E8883C0F89F8"
-test_hash "xxh32"    "B30D56B4"
-test_hash "xxh64"    "C6F2D2DD0AD64FB6"
-test_hash "xxh3_64"  "F34099EDE96B5581"
-test_hash "xxh3_128" "4AF3DA69F61E14CF26F4C14B6B6BFDB4"
+test_hash "md5"         "827CCB0EEA8A706C4C34A16891F84E7B"
+test_hash "sha1"        "8CB2237D0679CA88DB6464EAC60DA96345513964"
+test_hash "sha224"      "A7470858E79C282BC2F6ADFD831B132672DFD1224C1E78CBF5BCD057"
+test_hash "sha256"      "5994471ABB01112AFCC18159F6CC74B4F511B99806DA59B3CAF5A9C173CACFC5"
+test_hash "sha384"      "0FA76955ABFA9DAFD83FACCA8343A92AA09497F98101086611B0BFA95DBC0DCC661D62E9568A5A032BA81960F3E55D4A"
+test_hash "sha512"      "3627909A29C31381A071EC27F7C9CA97726182AED29A7DDD2E54353322CFB30ABB9E3A6DF2AC2C20FE23436311D678564D0C8D305930575F60E2D3D048184D79"
+test_hash "sha512_224"  "A7E0FC5CFF225F68E762EB5DE497D11554775F6BFAE7ACF5CFC4C209"
+test_hash "sha512_256"  "EE039E3BED452CEB91427FCEF9F0E01B6AF73272C8A103E5BB853C9E3170EDEF"
+test_hash "sha3_224"    "94CC697550F5C7399D179E206CF1E7BF90E17DE8A87FF0F9368EC839"
+test_hash "sha3_256"    "7D4E3EEC80026719639ED4DBA68916EB94C7A49A053E05C8F9578FE4E5A3D7EA"
+test_hash "sha3_384"    "161609F9697539EDD5E03B6F5BFD1735F5C6037E0B00027C45A80386D5EBDCD3EB4BDE062710914C7F37BD45F1C8021D"
+test_hash "sha3_512"    "0A2A1719BF3CE682AFDBEDF3B23857818D526EFBE7FCB372B31347C26239A0F916C398B7AD8DD0EE76E8E388604D0B0F925D5E913AD2D3165B9B35B3844CD5E6"
+test_hash "keccak256"   "1841D653F9C4EDDA9D66A7E7737B39763D6BD40F569A3EC6859D3305B72310E6"
+test_hash "sm3"         "91A7ADDE5B0919D53FFB7DC7253F9F345C3C902A759FE5A2493C70ABB7E25095"
+test_hash "streebog256" "5E4A6C025B365DAECC4CAF398C8D05BD05C8EA489AC2324010F0EBDCB7EC1C1D"
+test_hash "streebog512" "48805FF5CE8CE74F322456F291843726423A65CAFBB5B6B87D356A404C9DAEB17345E3CAE0405CF17C3298C8017E7C60CB033852CD968BAF510816073D7751F2"
+test_hash "blake2b512"  "8B28F613FA1CCDB1D303704839A0BB196424F425BADFA4E4F43808F6812B6BCC0AE43374383BB6E46294D08155A64ACBAD92084387C73F696F00368EA106EBB4"
+test_hash "blake2s256"  "A076A699190673026FE44F7B523D321FCAE79E70945007BDB1C86295A11C4135"
+test_hash "blake3"      "86F2D80ABE9C3F7B4A1A57A8D1130FA8DC08C81604833CE1212DC039B010D9E4"
+test_hash "crc32"       "CBF53A1C"
+test_hash "crc32c"      "18D12335"
+test_hash "crc64_ecma"  "AF0107D86177F1D5"
+test_hash "crc64_xz"    "5DA746FFA5045CE9"
+test_hash "adler32"     "02F80100"
+test_hash "fnv1a"       "E575E8883C0F89F8"
+test_hash "fnv1a_32"    "43C2C0D8"
+test_hash "fnv1a_128"   "0F8E34F8DA83D94F7080BE242E232050"
+test_hash "fnv1_32"     "DEEE36FA"
+test_hash "fnv1_64"     "A92F4455DA95A77A"
+test_hash "fnv1_128"    "F2E4F90BA383D94F708038F78848C2DA"
+test_hash "xxh32"       "B30D56B4"
+test_hash "xxh64"       "C6F2D2DD0AD64FB6"
+test_hash "xxh3_64"     "F34099EDE96B5581"
+test_hash "xxh3_128"    "4AF3DA69F61E14CF26F4C14B6B6BFDB4"
+
+test_one "SELECT sha256_trunc_hex(8, '12345');"                                                                             "5994471ABB01112A"
+test_one "SELECT hex(sha512_256_trunc_concat(16, '12345'));"                                                                "EE039E3BED452CEB91427FCEF9F0E01B"
+test_one "SELECT md5_trunc_hex(4, '12345');"                                                                                "827CCB0E"
+test_one "SELECT hmac_sha256_hex('key', '12345');"                                                                          "AB99A81F96D56F3B99596E3168B1ADE13E02AB0AAE08898B8AA4E3377C9E29D1"
+test_one "SELECT hex(hmac_sha512_256_concat('key', '12345'));"                                                              "725F7A742C1099ABF4F03976E6E0B6F0E0ED1F081420E061BDC470F55C944A7D"
+test_one "SELECT shake128_hex(16, '12345');"                                                                                "406A6EBF1A5AAECF405F9BB5A51ECC37"
+test_one "SELECT hex(shake128_concat(16, '12345'));"                                                                        "406A6EBF1A5AAECF405F9BB5A51ECC37"
+test_one "SELECT kmac128_hex('key', '', '12345');"                                                                          "CD121DDF019084F1EC8183F63F53DB433499FA9D60B23B3B0A753EF80854C055"
+test_one "SELECT hex(kmac128_concat('key', '', '12345'));"                                                                  "CD121DDF019084F1EC8183F63F53DB433499FA9D60B23B3B0A753EF80854C055"
+test_one "SELECT blake2b_hex(16, '12345');"                                                                                 "000AFBF3F1F0BDECBB9748DAD8B42825"
+test_one "SELECT hex(blake2b_concat(16, '12345'));"                                                                         "000AFBF3F1F0BDECBB9748DAD8B42825"
+test_one "SELECT blake2s_keyed_hex(16, 'key', 'salt', 'pers', '12345');"                                                    "325B4928F4504A574DC1274660A6886D"
+test_one "SELECT hex(blake2s_keyed_concat(16, 'key', 'salt', 'pers', '12345'));"                                            "325B4928F4504A574DC1274660A6886D"
+test_one "SELECT blake3_keyed_hex('whats the Elvish word for friend', '12345');"                                            "E8CF15EF95A59BECA2DDDB70AEC508F7771CAB5A32D5164B76A66E476FFF4650"
+test_one "SELECT blake3_derive_key_hex('ctx', '12345');"                                                                    "F4472A5DA1F18C773E2168F2A227239FB277B65100450C17B1F0AF32FED26750"
+test_one "SELECT hex(blake3_xof_concat(16, '12345'));"                                                                      "86F2D80ABE9C3F7B4A1A57A8D1130FA8"
+test_one "SELECT xxh32_seeded_hex(42, '12345');"                                                                            "D5B4B8CE"
+test_one "SELECT hex(xxh64_seeded_concat(42, '12345'));"                                                                    "BAD096C08D9E0D57"
+test_one "SELECT crc32_int('12345');"                                                                                       "3421846044"
+test_one "SELECT crc32_int_concat('12345');"                                                                                "3421846044"
+test_one "SELECT murmur3_32_hex(0, '12345');"                                                                               "13A51193"
+test_one "SELECT murmur3_32_int_concat(0, '12345');"                                                                        "329585043"
+test_one "SELECT hex(murmur3_128_concat(0, '12345'));"                                                                      "CBDF216B173AF820F4A95C32415C3CF1"
+test_one "SELECT siphash24_hex(x'000102030405060708090A0B0C0D0E0F', '12345');"                                              "51FFA8E18E845F18"
+test_one "SELECT siphash24_int_concat(x'000102030405060708090A0B0C0D0E0F', '12345');"                                       "1756268128903954257"
+test_one "SELECT hex(siphash128_concat(x'000102030405060708090A0B0C0D0E0F', '12345'));"                                     "082683975D051C4F445B4D8D255E2913"
+test_one "SELECT highwayhash64_hex(x'000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F', '12345');"          "76C58403BDC6C632"
+test_one "SELECT hex(highwayhash128_concat(x'000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F', '12345'));" "8C55E07855022CA8DD60D89DFA76B326"
+test_one "SELECT wyhash_final4_hex(0, '12345');"                                                                            "D042C140A853A77B"
+test_one "SELECT rapidhash_v3_int_concat(0, '12345');"                                                                      "-1406859813244774268"
+test_one "SELECT hex(komihash_v5_concat(0, '12345'));"                                                                      "8CF77C38A64918B1"
+test_one "SELECT kt128_hex(16, '', '12345');"                                                                               "5F1FD9FCC31698E8A844CD9B3D283330"
+test_one "SELECT hex(turboshake128_concat(16, 31, '12345'));"                                                               "C1124D4036A0E0EE86BCC4C9CD5EE61B"
+test_one "SELECT ascon_hash256_hex('12345');"                                                                               "89D18F802C2698B5D2892967DDD30A3898C2CA41B7E3EA42C86DE761725F0D99"
+test_one "SELECT hex(ascon_xof128_concat(16, '12345'));"                                                                    "5EB7831CC2CC48267E9E39DB0995C99C"
+test_one "SELECT ascon_cxof128_hex(16, 'app', '12345');"                                                                    "F2E52E5886E0C358E39539B878B28E7B"